@@ -0,0 +1,129 @@
+use criterion::black_box;
+use criterion::Criterion;
+use puzzle_input::Source;
+use serde_json::Value;
+use solution::Solution;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[path = "../src/duration.rs"]
+mod duration;
+
+use crate::duration::format_duration;
+
+const PHASES: [&str; 3] = ["parse", "part1", "part2"];
+
+fn bench_day<S: Solution>(c: &mut Criterion, name: &str, source: Source, sample_size: usize) {
+    let input = source.read().unwrap();
+    let parsed = S::parse(&input).unwrap();
+
+    let mut group = c.benchmark_group(name);
+    group.sample_size(sample_size);
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+/// Changes whose frequencies fall in distinct residue classes, apart from
+/// the first two which are `passes` drifts apart, so the first repeat only
+/// comes after that many passes. The drift is `count`, and a fixed linear
+/// congruential generator keeps the input the same between runs.
+fn generated_changes(count: usize, passes: i32) -> Vec<i32> {
+    let mut state: u64 = 2018;
+    let mut random = |bound: usize| {
+        state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        return (state >> 33) as usize % bound;
+    };
+
+    let drift = count as i32;
+    let mut residues: Vec<i32> = (1..drift).collect();
+    for i in (1..residues.len()).rev() {
+        residues.swap(i, random(i + 1));
+    }
+
+    let mut sums: Vec<i32> = residues
+        .iter()
+        .map(|residue| residue + drift * (random(50) as i32 - 25))
+        .collect();
+    sums[0] = sums[1] - passes * drift;
+    sums.push(drift);
+
+    return sums
+        .iter()
+        .scan(0, |previous, &sum| {
+            let change = sum - *previous;
+            *previous = sum;
+            return Some(change);
+        })
+        .collect();
+}
+
+fn bench_first_repeat(c: &mut Criterion) {
+    let changes = generated_changes(10000, 500);
+    assert_eq!(
+        day1::frequency::find_first_repeat(&changes),
+        day1::frequency::find_first_repeat_by_residue(&changes)
+    );
+
+    let mut group = c.benchmark_group("day1_first_repeat");
+    group.sample_size(10);
+    group.bench_function("simulation", |b| {
+        b.iter(|| day1::frequency::find_first_repeat(black_box(&changes)))
+    });
+    group.bench_function("residue", |b| {
+        b.iter(|| day1::frequency::find_first_repeat_by_residue(black_box(&changes)))
+    });
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    bench_day::<day1::Day1>(c, "day1", day1::INPUT, 100);
+    bench_day::<day2::Day2>(c, "day2", day2::INPUT, 100);
+    bench_day::<day3::Day3>(c, "day3", day3::INPUT, 20);
+    bench_day::<day4::Day4>(c, "day4", day4::INPUT, 100);
+    bench_day::<day5::Day5>(c, "day5", day5::INPUT, 10);
+    bench_day::<day6::Day6>(c, "day6", day6::INPUT, 20);
+    bench_day::<day7::Day7>(c, "day7", day7::INPUT, 100);
+    bench_first_repeat(c);
+}
+
+fn criterion_directory() -> PathBuf {
+    let target = env::var("CARGO_TARGET_DIR")
+        .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/../target").to_string());
+
+    return PathBuf::from(target).join("criterion");
+}
+
+/// The mean time in nanoseconds of the latest run of `day`/`phase`.
+fn mean_estimate(day: &str, phase: &str) -> Option<f64> {
+    let path = criterion_directory().join(day).join(phase).join("new/estimates.json");
+    let estimates: Value = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+
+    return estimates["mean"]["point_estimate"].as_f64();
+}
+
+fn print_summary() {
+    println!("{:<6}{:>12}{:>12}{:>12}", "day", "parse", "part1", "part2");
+
+    for day in 1..=7 {
+        let day = format!("day{}", day);
+        let times = PHASES
+            .iter()
+            .map(|phase| mean_estimate(&day, phase).map_or("-".to_string(), |nanoseconds| format_duration(Duration::from_nanos(nanoseconds as u64))))
+            .map(|time| format!("{:>12}", time))
+            .collect::<String>();
+
+        println!("{:<6}{}", day, times);
+    }
+}
+
+fn main() {
+    let mut criterion = Criterion::default().configure_from_args();
+    benches(&mut criterion);
+    criterion.final_summary();
+
+    print_summary();
+}
@@ -0,0 +1,92 @@
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+
+pub const ANSWERS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+/// Known-good answers, keyed by day, part and the hash of the input they
+/// were computed from.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Default)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>);
+
+impl Answers {
+    pub fn load(path: &str) -> Result<Answers, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(ref error) if error.kind() == ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(error) => return Err(format!("Could not read {}: {}", path, error)),
+        };
+
+        return toml::from_str(&content).map_err(|error| format!("Could not parse {}: {}", path, error));
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let content = toml::to_string(self).map_err(|error| error.to_string())?;
+        return fs::write(path, content).map_err(|error| format!("Could not write {}: {}", path, error));
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&str> {
+        return self
+            .0
+            .get(&format!("day{}", day))
+            .and_then(|parts| parts.get(&format!("part{}", part)))
+            .and_then(|inputs| inputs.get(input_hash))
+            .map(String::as_str);
+    }
+
+    pub fn insert(&mut self, day: u8, part: u8, input_hash: &str, answer: &str) {
+        self.0
+            .entry(format!("day{}", day))
+            .or_default()
+            .entry(format!("part{}", part))
+            .or_default()
+            .insert(input_hash.to_string(), answer.to_string());
+    }
+}
+
+/// A stable 64-bit FNV-1a hash of `input`, as hex.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    return format!("{:016x}", hash);
+}
+
+#[cfg(test)]
+mod test {
+    use super::input_hash;
+    use super::Answers;
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(input_hash(""), "cbf29ce484222325");
+        assert_eq!(input_hash("a"), "af63dc4c8601ec8c");
+        assert_ne!(input_hash("+1\n-2\n"), input_hash("+1\n-2"));
+    }
+
+    #[test]
+    fn test_get_and_insert() {
+        let mut answers = Answers::default();
+        answers.insert(5, 2, "abc", "4552");
+
+        assert_eq!(answers.get(5, 2, "abc"), Some("4552"));
+        assert_eq!(answers.get(5, 1, "abc"), None);
+        assert_eq!(answers.get(5, 2, "def"), None);
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let mut answers = Answers::default();
+        answers.insert(1, 1, "cbf29ce484222325", "459");
+        answers.insert(7, 1, "af63dc4c8601ec8c", "ABDC");
+
+        let content = toml::to_string(&answers).unwrap();
+
+        assert_eq!(content, "[day1.part1]\ncbf29ce484222325 = \"459\"\n\n[day7.part1]\naf63dc4c8601ec8c = \"ABDC\"\n");
+        assert_eq!(toml::from_str::<Answers>(&content).unwrap(), answers);
+    }
+}
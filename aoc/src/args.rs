@@ -0,0 +1,306 @@
+use std::str::FromStr;
+
+use crate::calibrate::DEFAULT_INTERVAL;
+
+pub const USAGE: &str = "Usage: aoc run --day <day> [--part <part>] [--solver <name>] [--timings] [--format human|json] [--render <file>] [<input> | -]
+       aoc verify [--answers <file>] [--record]
+       aoc calibrate [--interval <n>] < <changes>
+       aoc inventory [--distance <k>] [<input> | -]";
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Format {
+    Human,
+    Json,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Command {
+    Run {
+        day: u8,
+        part: Option<u8>,
+        solver: Option<String>,
+        input_path: Option<String>,
+        timings: bool,
+        format: Format,
+        render_path: Option<String>,
+    },
+    Verify {
+        answers_path: Option<String>,
+        record: bool,
+    },
+    Calibrate {
+        interval: usize,
+    },
+    Inventory {
+        distance: usize,
+        input_path: Option<String>,
+    },
+}
+
+fn parse_number<T: FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("Missing value for {}", flag))?;
+    return value
+        .parse::<T>()
+        .map_err(|_| format!("Invalid value for {}: {}", flag, value));
+}
+
+fn parse_format(value: Option<String>) -> Result<Format, String> {
+    return match value.as_deref() {
+        Some("human") => Ok(Format::Human),
+        Some("json") => Ok(Format::Json),
+        Some(value) => Err(format!("Invalid value for --format: {}", value)),
+        None => Err("Missing value for --format".to_string()),
+    };
+}
+
+fn parse_run<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut solver = None;
+    let mut input_path = None;
+    let mut timings = false;
+    let mut format = Format::Human;
+    let mut render_path = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number("--day", args.next())?),
+            "--part" => part = Some(parse_number("--part", args.next())?),
+            "--solver" => solver = Some(args.next().ok_or("Missing value for --solver")?),
+            "--timings" => timings = true,
+            "--format" => format = parse_format(args.next())?,
+            "--render" => render_path = Some(args.next().ok_or("Missing value for --render")?),
+            _ if arg.starts_with("--") => return Err(format!("Unknown option: {}", arg)),
+            _ if input_path.is_none() => input_path = Some(arg),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    return Ok(Command::Run {
+        day: day.ok_or("Missing --day")?,
+        part,
+        solver,
+        input_path,
+        timings,
+        format,
+        render_path,
+    });
+}
+
+fn parse_verify<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut answers_path = None;
+    let mut record = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => answers_path = Some(args.next().ok_or("Missing value for --answers")?),
+            "--record" => record = true,
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    return Ok(Command::Verify {
+        answers_path,
+        record,
+    });
+}
+
+fn parse_calibrate<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut interval = DEFAULT_INTERVAL;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--interval" => interval = parse_number("--interval", args.next())?,
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    return Ok(Command::Calibrate { interval });
+}
+
+fn parse_inventory<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+    let mut distance = 1;
+    let mut input_path = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--distance" => distance = parse_number("--distance", args.next())?,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option: {}", arg)),
+            _ if input_path.is_none() => input_path = Some(arg),
+            _ => return Err(format!("Unexpected argument: {}", arg)),
+        }
+    }
+
+    return Ok(Command::Inventory { distance, input_path });
+}
+
+impl Command {
+    pub fn from_args<I: Iterator<Item = String>>(mut args: I) -> Result<Command, String> {
+        match args.next().as_deref() {
+            Some("run") => parse_run(args),
+            Some("verify") => parse_verify(args),
+            Some("calibrate") => parse_calibrate(args),
+            Some("inventory") => parse_inventory(args),
+            Some(command) => Err(format!("Unknown command: {}", command)),
+            None => Err("Missing command".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Command;
+    use super::Format;
+
+    fn parse(input: &str) -> Result<Command, String> {
+        Command::from_args(input.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_run() {
+        let expected = Command::Run {
+            day: 5,
+            part: Some(2),
+            solver: None,
+            input_path: Some("input.txt".to_string()),
+            timings: false,
+            format: Format::Human,
+            render_path: None,
+        };
+
+        assert_eq!(parse("run --day 5 --part 2 input.txt"), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_run_any_order() {
+        let expected = Command::Run {
+            day: 7,
+            part: Some(1),
+            solver: None,
+            input_path: Some("input".to_string()),
+            timings: false,
+            format: Format::Human,
+            render_path: None,
+        };
+
+        assert_eq!(parse("run input --part 1 --day 7"), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_run_default_input() {
+        let expected = Command::Run {
+            day: 3,
+            part: Some(2),
+            solver: None,
+            input_path: None,
+            timings: false,
+            format: Format::Human,
+            render_path: None,
+        };
+
+        assert_eq!(parse("run --day 3 --part 2"), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_verify() {
+        let expected = Command::Verify {
+            answers_path: Some("answers.toml".to_string()),
+            record: true,
+        };
+
+        assert_eq!(parse("verify --record --answers answers.toml"), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_run_all_parts_with_timings() {
+        let expected = Command::Run {
+            day: 5,
+            part: None,
+            solver: None,
+            input_path: Some("input".to_string()),
+            timings: true,
+            format: Format::Human,
+            render_path: None,
+        };
+
+        assert_eq!(parse("run --day 5 --timings input"), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_run_json() {
+        let expected = Command::Run {
+            day: 1,
+            part: Some(2),
+            solver: None,
+            input_path: None,
+            timings: false,
+            format: Format::Json,
+            render_path: None,
+        };
+
+        assert_eq!(parse("run --day 1 --part 2 --format json"), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_invalid_format() {
+        assert_eq!(parse("run --day 1 --format xml"), Err("Invalid value for --format: xml".to_string()));
+    }
+
+    #[test]
+    fn test_parse_missing_day() {
+        assert_eq!(parse("run --part 1 input"), Err("Missing --day".to_string()));
+    }
+
+    #[test]
+    fn test_parse_invalid_day() {
+        assert_eq!(parse("run --day five --part 1 input"), Err("Invalid value for --day: five".to_string()));
+    }
+
+    #[test]
+    fn test_parse_run_solver() {
+        let expected = Command::Run {
+            day: 1,
+            part: Some(2),
+            solver: Some("residue".to_string()),
+            input_path: None,
+            timings: true,
+            format: Format::Human,
+            render_path: None,
+        };
+
+        assert_eq!(parse("run --day 1 --part 2 --solver residue --timings"), Ok(expected));
+    }
+
+    #[test]
+    fn test_parse_run_render() {
+        let expected = Command::Run {
+            day: 3,
+            part: None,
+            solver: None,
+            input_path: None,
+            timings: false,
+            format: Format::Human,
+            render_path: Some("out.png".to_string()),
+        };
+
+        assert_eq!(parse("run --day 3 --render out.png"), Ok(expected));
+        assert_eq!(parse("run --day 3 --render"), Err("Missing value for --render".to_string()));
+    }
+
+    #[test]
+    fn test_parse_calibrate() {
+        assert_eq!(parse("calibrate"), Ok(Command::Calibrate { interval: 1000 }));
+        assert_eq!(parse("calibrate --interval 0"), Ok(Command::Calibrate { interval: 0 }));
+        assert_eq!(parse("calibrate --interval -1"), Err("Invalid value for --interval: -1".to_string()));
+    }
+
+    #[test]
+    fn test_parse_inventory() {
+        let expected = Command::Inventory {
+            distance: 2,
+            input_path: Some("-".to_string()),
+        };
+
+        assert_eq!(parse("inventory - --distance 2"), Ok(expected));
+    }
+}
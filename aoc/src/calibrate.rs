@@ -0,0 +1,104 @@
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+
+use day1::calibrator::Calibrator;
+
+use crate::diagnostic;
+
+pub const DEFAULT_INTERVAL: usize = 1000;
+
+fn write_error(error: io::Error) -> String {
+    return format!("Failed to write output: {}", error);
+}
+
+/// Feeds day 1 frequency changes through a calibrator as they arrive,
+/// writing the running total every `interval` changes (never, if zero) and
+/// the first repeated frequency as soon as it is reached.
+pub fn calibrate<R: BufRead, W: Write>(input: R, mut output: W, interval: usize) -> Result<(), String> {
+    let mut calibrator: Calibrator<i64> = Calibrator::new();
+
+    for (number, line) in input.lines().enumerate() {
+        let line = line.map_err(|error| format!("Failed to read <stdin>: {}", error))?;
+        let changes: Vec<i64> = day1::parse::parse_line(&line)
+            .map_err(|error| diagnostic::render_line(&error.at_line(number + 1), "<stdin>", &line))?;
+
+        for change in changes {
+            let repeated = calibrator
+                .apply(&change)
+                .map_err(|error| format!("line {}: {}", number + 1, error.message()))?;
+
+            if repeated {
+                writeln!(
+                    output,
+                    "first repeat: frequency {} after {} changes",
+                    calibrator.frequency(),
+                    calibrator.applied()
+                )
+                .map_err(write_error)?;
+            }
+            if interval > 0 && calibrator.applied().is_multiple_of(interval) {
+                writeln!(
+                    output,
+                    "{} changes: frequency {}",
+                    calibrator.applied(),
+                    calibrator.frequency()
+                )
+                .map_err(write_error)?;
+            }
+        }
+        output.flush().map_err(write_error)?;
+    }
+
+    if calibrator.first_repeat().is_none() {
+        writeln!(output, "no frequency repeated").map_err(write_error)?;
+    }
+    writeln!(
+        output,
+        "final: frequency {} after {} changes",
+        calibrator.frequency(),
+        calibrator.applied()
+    )
+    .map_err(write_error)?;
+
+    return Ok(());
+}
+
+#[cfg(test)]
+mod test {
+    use super::calibrate;
+
+    fn run(input: &str, interval: usize) -> Result<String, String> {
+        let mut output = Vec::new();
+        calibrate(input.as_bytes(), &mut output, interval)?;
+        return Ok(String::from_utf8(output).unwrap());
+    }
+
+    #[test]
+    fn test_calibrate() {
+        let expected = [
+            "2 changes: frequency 6",
+            "4 changes: frequency 8",
+            "6 changes: frequency 7",
+            "first repeat: frequency 10 after 7 changes",
+            "final: frequency 10 after 7 changes",
+            "",
+        ];
+
+        assert_eq!(run("+3\n+3, +4\n-2 -4\n+3\n+3\n", 2), Ok(expected.join("\n")));
+    }
+
+    #[test]
+    fn test_calibrate_without_repeat() {
+        let expected = ["no frequency repeated", "final: frequency 3 after 2 changes", ""];
+
+        assert_eq!(run("+1\n\n+2 # last\n", 0), Ok(expected.join("\n")));
+    }
+
+    #[test]
+    fn test_calibrate_invalid_change() {
+        let error = run("+1\n+x\n", 0).unwrap_err();
+
+        assert!(error.starts_with("error: invalid number\n --> <stdin>:2:1\n"));
+    }
+}
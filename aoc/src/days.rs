@@ -0,0 +1,162 @@
+use puzzle_input::Source;
+use serde_json::Value;
+use solution::solve;
+use solution::Error;
+use solution::Solution;
+
+use crate::json::ToJson;
+use crate::render;
+use crate::timings::measure;
+use crate::timings::Timing;
+
+/// An answer as people read it, and as `--format json` shows it.
+#[derive(PartialEq, Debug)]
+pub struct Answer {
+    pub text: String,
+    pub json: Value,
+}
+
+impl Answer {
+    fn new<T: ToJson>(answer: T) -> Answer {
+        return Answer {
+            text: answer.to_string(),
+            json: answer.to_json(),
+        };
+    }
+}
+
+pub struct Execution {
+    pub answers: Vec<(u8, Result<Answer, &'static str>)>,
+    pub timings: Vec<Timing>,
+    /// How drawing the parsed input went, when it was asked for.
+    pub rendered: Result<(), String>,
+}
+
+/// Draws a day's parsed input, for `--render <file>`.
+pub type Render<'a, I> = &'a dyn Fn(&I) -> Result<(), String>;
+
+/// Parses `input` once, solves each of `parts`, timing every phase, and
+/// hands the parsed input to `render`.
+pub fn execute<S>(parts: &[u8], input: &str, render: Option<Render<S::Input>>) -> Result<Execution, Error>
+where
+    S: Solution,
+    S::PartOne: ToJson,
+    S::PartTwo: ToJson,
+{
+    let (parsed, timing) = measure("parse", || S::parse(input));
+    let parsed = parsed.map_err(Error::Parse)?;
+
+    let mut execution = Execution {
+        answers: Vec::new(),
+        timings: vec![timing],
+        rendered: Ok(()),
+    };
+
+    for &part in parts {
+        let (answer, timing) = match part {
+            1 => measure("part 1", || S::part1(&parsed).map(Answer::new)),
+            2 => measure("part 2", || S::part2(&parsed).map(Answer::new)),
+            _ => return Err(Error::Solve("Part must be 1 or 2")),
+        };
+
+        execution.answers.push((part, answer));
+        execution.timings.push(timing);
+    }
+
+    if let Some(render) = render {
+        execution.rendered = render(&parsed);
+    }
+
+    return Ok(execution);
+}
+
+/// Runs a solver on the parts and input, rendering to the file at the path
+/// if one is given and the day can render.
+pub type Execute = fn(&[u8], &str, Option<&str>) -> Result<Execution, Error>;
+
+/// One way of solving a day, picked with `--solver <name>`.
+pub struct Solver {
+    pub name: &'static str,
+    pub execute: Execute,
+}
+
+pub struct Day {
+    pub number: u8,
+    pub input: Source<'static>,
+    pub solve: fn(u8, &str) -> Result<String, Error>,
+    /// The first solver is the default, and the one `solve` uses.
+    pub solvers: &'static [Solver],
+    /// Whether the day was given a render hook.
+    pub renders: bool,
+}
+
+impl Day {
+    pub fn solver(&self, name: Option<&str>) -> Result<&Solver, String> {
+        let name = match name {
+            Some(name) => name,
+            None => return Ok(&self.solvers[0]),
+        };
+
+        return self.solvers.iter().find(|solver| solver.name == name).ok_or_else(|| {
+            let names: Vec<&str> = self.solvers.iter().map(|solver| solver.name).collect();
+            format!("No solver `{}` for day {} (available: {})", name, self.number, names.join(", "))
+        });
+    }
+}
+
+/// `day!(n, krate::Type)`, optionally followed by named solvers and by
+/// `render: <fn(&Input, &str) -> Result<(), String>>`, which every solver
+/// calls with its parsed input when `--render` is given.
+macro_rules! day {
+    ($number:expr, $krate:ident :: $solution:ident) => {
+        day!($number, $krate::$solution, ["default" => $krate::$solution])
+    };
+    ($number:expr, $krate:ident :: $solution:ident, [$($name:expr => $variant:ty),+]) => {
+        Day {
+            number: $number,
+            input: $krate::INPUT,
+            solve: solve::<$krate::$solution>,
+            solvers: &[$(Solver {
+                name: $name,
+                execute: |parts, input, _| execute::<$variant>(parts, input, None),
+            }),+],
+            renders: false,
+        }
+    };
+    ($number:expr, $krate:ident :: $solution:ident, [$($name:expr => $variant:ty),+], render: $render:path) => {
+        Day {
+            number: $number,
+            input: $krate::INPUT,
+            solve: solve::<$krate::$solution>,
+            solvers: &[$(Solver {
+                name: $name,
+                execute: |parts, input, render_path| match render_path {
+                    Some(path) => execute::<$variant>(parts, input, Some(&|parsed: &_| $render(parsed, path))),
+                    None => execute::<$variant>(parts, input, None),
+                },
+            }),+],
+            renders: true,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, day1::Day1, [
+        "simulation" => day1::Day1,
+        "residue" => day1::Day1ByResidue,
+        "simulation-i128" => day1::Day1<i128>,
+        "residue-i128" => day1::Day1ByResidue<i128>,
+        "simulation-big" => day1::Day1<day1::BigInt>,
+        "residue-big" => day1::Day1ByResidue<day1::BigInt>
+    ]),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3, ["grid" => day3::Day3, "sweep" => day3::Day3Sweep], render: render::render),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+];
+
+pub fn get(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
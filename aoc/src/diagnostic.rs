@@ -0,0 +1,70 @@
+use solution::ParseError;
+
+/// Renders `error` compiler style: the location, the offending line with a
+/// caret under the bad span, and the expected format of the line.
+pub fn render(error: &ParseError, source: &str, input: &str) -> String {
+    return render_line(error, source, input.lines().nth(error.line - 1).unwrap_or(""));
+}
+
+/// Like `render`, for when only the offending line is at hand.
+pub fn render_line(error: &ParseError, source: &str, line: &str) -> String {
+    let number = error.line.to_string();
+    let gutter = " ".repeat(number.len());
+    let carets = "^".repeat(error.text.chars().count().max(1));
+
+    let mut output = format!("error: {}\n", error.kind);
+    output += &format!("{}--> {}:{}:{}\n", gutter, source, error.line, error.column);
+    output += &format!("{} |\n", gutter);
+    output += &format!("{} | {}\n", number, line);
+    output += &format!("{} | {}{}\n", gutter, " ".repeat(error.column - 1), carets);
+
+    if let Some(hint) = error.hint {
+        output += &format!("{} = hint: {}\n", gutter, hint);
+    }
+
+    return output;
+}
+
+#[cfg(test)]
+mod test {
+    use super::render;
+    use solution::ParseError;
+    use solution::ParseErrorKind;
+
+    #[test]
+    fn test_render() {
+        let input = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4a\n";
+        let error = ParseError::new(ParseErrorKind::InvalidNumber, 13, "4a")
+            .at_line(2)
+            .with_hint("expected `#<id> @ <x>,<y>: <w>x<h>`");
+
+        let expected = [
+            "error: invalid number",
+            " --> input:2:13",
+            "  |",
+            "2 | #2 @ 3,1: 4x4a",
+            "  |             ^^",
+            "  = hint: expected `#<id> @ <x>,<y>: <w>x<h>`",
+            "",
+        ];
+
+        assert_eq!(render(&error, "input", input), expected.join("\n"));
+    }
+
+    #[test]
+    fn test_render_empty_span() {
+        let input = "Step C must be";
+        let error = ParseError::new(ParseErrorKind::InvalidFormat, 15, "");
+
+        let expected = [
+            "error: invalid format",
+            " --> input:1:15",
+            "  |",
+            "1 | Step C must be",
+            "  |               ^",
+            "",
+        ];
+
+        assert_eq!(render(&error, "input", input), expected.join("\n"));
+    }
+}
@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+/// A duration in the largest unit that keeps it at least one, shared by the
+/// `--timings` report and the benchmark summary.
+pub fn format_duration(duration: Duration) -> String {
+    let nanoseconds = duration.as_nanos() as f64;
+    let units = [("s", 1e9), ("ms", 1e6), ("µs", 1e3)];
+
+    return units
+        .iter()
+        .find(|&&(_, scale)| nanoseconds >= scale)
+        .map(|&(unit, scale)| format!("{:.2} {}", nanoseconds / scale, unit))
+        .unwrap_or_else(|| format!("{:.0} ns", nanoseconds));
+}
@@ -0,0 +1,62 @@
+use day2::checksum;
+use day2::cluster;
+use day2::BoxId;
+
+/// A report on a list of box IDs: the clusters of near IDs with their
+/// common letters, the IDs behind each count of the checksum, and the
+/// letter histogram of every ID.
+pub fn render(ids: &[BoxId], max_distance: usize) -> String {
+    let mut output = format!("clusters within distance {}:\n", max_distance);
+    let clusters = cluster::clusters(ids, max_distance);
+    if clusters.is_empty() {
+        output += "  none\n";
+    }
+    for cluster in clusters {
+        output += &format!("  {}: {}\n", cluster.common(), cluster.ids.join(", "));
+    }
+
+    let checksum = checksum::checksum(ids);
+    let factors: Vec<String> = checksum.counts.values().map(|count| count.to_string()).collect();
+    output += &format!("\nchecksum: {} = {}\n", factors.join(" × "), checksum.product);
+    for (multiplicity, contributors) in checksum::contributors(ids, &checksum::DEFAULT_MULTIPLICITIES) {
+        output += &format!("  {}-count ({}): {}\n", multiplicity, contributors.len(), contributors.join(", "));
+    }
+
+    output += "\nhistograms:\n";
+    for id in ids {
+        let mut letters: Vec<(char, usize)> = checksum::histogram(id).into_iter().collect();
+        letters.sort_unstable();
+        let letters: Vec<String> = letters.iter().map(|(letter, count)| format!("{}{}", letter, count)).collect();
+        output += &format!("  {}  {}\n", id, letters.join(" "));
+    }
+
+    return output;
+}
+
+#[cfg(test)]
+mod test {
+    use super::render;
+    use day2::BoxId;
+
+    #[test]
+    fn test_render() {
+        let ids: Vec<BoxId> = ["abcde", "fghij", "fguij", "aabbb"].iter().map(|id| id.parse().unwrap()).collect();
+        let expected = [
+            "clusters within distance 1:",
+            "  fgij: fghij, fguij",
+            "",
+            "checksum: 1 × 1 = 1",
+            "  2-count (1): aabbb",
+            "  3-count (1): aabbb",
+            "",
+            "histograms:",
+            "  abcde  a1 b1 c1 d1 e1",
+            "  fghij  f1 g1 h1 i1 j1",
+            "  fguij  f1 g1 i1 j1 u1",
+            "  aabbb  a2 b3",
+            "",
+        ];
+
+        assert_eq!(render(&ids, 1), expected.join("\n"));
+    }
+}
@@ -0,0 +1,99 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::days;
+
+/// How an answer appears in `--format json`. Every answer type a solver can
+/// return needs an impl, so the schema only changes here.
+pub trait ToJson: Display {
+    fn to_json(&self) -> Value;
+}
+
+/// Answers shown as a string of their text, as they always have been.
+macro_rules! to_json_as_text {
+    ($($answer:ty),+) => {
+        $(impl ToJson for $answer {
+            fn to_json(&self) -> Value {
+                return Value::String(self.to_string());
+            }
+        })+
+    };
+}
+
+to_json_as_text!(i32, u32, i64, i128, usize, String, day1::BigInt);
+
+/// The ids of the intact claims, as an array even when there is one or none.
+impl ToJson for day3::IntactClaims {
+    fn to_json(&self) -> Value {
+        return Value::from(self.0.clone());
+    }
+}
+
+#[derive(Serialize)]
+struct Answer<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    elapsed_ms: f64,
+}
+
+/// A single line JSON object describing the answer to one part.
+pub fn answer(day: u8, part: u8, answer: &Result<days::Answer, &str>, elapsed: Duration) -> String {
+    let answer = Answer {
+        day,
+        part,
+        answer: answer.as_ref().ok().map(|answer| answer.json.clone()),
+        error: answer.as_ref().err().copied(),
+        elapsed_ms: elapsed.as_secs_f64() * 1000.0,
+    };
+
+    return serde_json::to_string(&answer).unwrap();
+}
+
+#[cfg(test)]
+mod test {
+    use super::answer;
+    use super::ToJson;
+    use crate::days::Answer;
+    use day3::IntactClaims;
+    use serde_json::Value;
+    use std::time::Duration;
+
+    fn plain(text: &str) -> Answer {
+        return Answer { text: text.to_string(), json: Value::String(text.to_string()) };
+    }
+
+    #[test]
+    fn test_answer() {
+        let line = answer(5, 2, &Ok(plain("4552")), Duration::from_micros(1500));
+        assert_eq!(line, r#"{"day":5,"part":2,"answer":"4552","elapsed_ms":1.5}"#);
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(4552usize.to_json(), Value::from("4552"));
+        assert_eq!("abc".to_string().to_json(), Value::from("abc"));
+        assert_eq!(IntactClaims(vec![560]).to_json(), Value::from(vec![560]));
+        assert_eq!(IntactClaims(vec![]).to_json(), Value::from(Vec::<i32>::new()));
+    }
+
+    #[test]
+    fn test_answer_list() {
+        let ids = Answer { text: "2 intact claims: 3, 12".to_string(), json: Value::from(vec![3, 12]) };
+        let none = Answer { text: "no intact claims".to_string(), json: Value::from(Vec::<i32>::new()) };
+
+        assert_eq!(answer(3, 2, &Ok(ids), Duration::from_millis(1)), r#"{"day":3,"part":2,"answer":[3,12],"elapsed_ms":1.0}"#);
+        assert_eq!(answer(3, 2, &Ok(none), Duration::from_millis(1)), r#"{"day":3,"part":2,"answer":[],"elapsed_ms":1.0}"#);
+        assert_eq!(answer(3, 2, &Ok(plain("560")), Duration::from_millis(1)), r#"{"day":3,"part":2,"answer":"560","elapsed_ms":1.0}"#);
+    }
+
+    #[test]
+    fn test_answer_error() {
+        let line = answer(2, 2, &Err("No matches found"), Duration::from_millis(2));
+        assert_eq!(line, r#"{"day":2,"part":2,"answer":null,"error":"No matches found","elapsed_ms":2.0}"#);
+    }
+}
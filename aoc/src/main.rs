@@ -0,0 +1,166 @@
+mod alloc;
+mod answers;
+mod args;
+mod calibrate;
+mod days;
+mod diagnostic;
+mod duration;
+mod inventory;
+mod json;
+mod render;
+mod timings;
+mod verify;
+
+use std::env::args;
+use std::io::stdin;
+use std::io::stdout;
+use std::process::exit;
+
+use puzzle_input::Source;
+use solution::Error;
+use solution::Solution;
+
+use crate::alloc::TrackingAllocator;
+use crate::answers::Answers;
+use crate::answers::ANSWERS_PATH;
+use crate::args::Command;
+use crate::args::Format;
+use crate::args::USAGE;
+use crate::verify::Status;
+
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
+fn run(
+    number: u8,
+    part: Option<u8>,
+    solver: Option<&str>,
+    input_path: Option<&str>,
+    timings: bool,
+    format: Format,
+    render_path: Option<&str>,
+) -> Result<(), String> {
+    let day = days::get(number).ok_or_else(|| format!("No solver for day {}", number))?;
+    let solver = day.solver(solver)?;
+    if let Some(path) = render_path {
+        if !day.renders {
+            return Err(format!("Day {} cannot be rendered", number));
+        }
+        render::ImageFormat::from_path(path)?;
+    }
+    let source = input_path.map_or(day.input, Source::from_arg);
+    let input = source.read().map_err(|error| error.to_string())?;
+    let parts = part.map_or(vec![1, 2], |part| vec![part]);
+
+    let execution = (solver.execute)(&parts, &input, render_path).map_err(|error| match error {
+        Error::Parse(error) => diagnostic::render(&error, &source.to_string(), &input),
+        Error::Solve(message) => message.to_string(),
+    })?;
+
+    let part_timings = execution.timings.iter().skip(1);
+    for ((part, answer), timing) in execution.answers.iter().zip(part_timings) {
+        match (format, answer) {
+            (Format::Human, Ok(answer)) => println!("{}", answer.text),
+            (Format::Human, Err(_)) => {}
+            (Format::Json, answer) => println!("{}", json::answer(number, *part, answer, timing.elapsed)),
+        }
+    }
+
+    match (format, timings) {
+        (Format::Human, true) => print!("\n{}", timings::render(&execution.timings)),
+        (Format::Json, true) => eprint!("{}", timings::render(&execution.timings)),
+        (_, false) => {}
+    }
+
+    execution.rendered?;
+
+    for (_, answer) in execution.answers {
+        answer?;
+    }
+
+    return Ok(());
+}
+
+fn verify(answers_path: Option<&str>, record: bool) -> Result<(), String> {
+    let answers_path = answers_path.unwrap_or(ANSWERS_PATH);
+    let mut answers = Answers::load(answers_path)?;
+    let outcomes = verify::verify(&answers);
+
+    for outcome in &outcomes {
+        println!("{}", outcome);
+    }
+
+    if record {
+        for outcome in outcomes.iter().filter(|outcome| outcome.status == Status::Unknown) {
+            if let Some(answer) = &outcome.answer {
+                answers.insert(outcome.day, outcome.part, &outcome.input_hash, answer);
+            }
+        }
+        answers.save(answers_path)?;
+    }
+
+    let failures = outcomes
+        .iter()
+        .filter(|outcome| match outcome.status {
+            Status::Failed(_) | Status::Regressed { .. } => true,
+            Status::Pass | Status::Unknown => false,
+        })
+        .count();
+
+    if failures > 0 {
+        return Err(format!("{} of {} answers failed or regressed", failures, outcomes.len()));
+    }
+
+    return Ok(());
+}
+
+fn inventory(distance: usize, input_path: Option<&str>) -> Result<(), String> {
+    let source = input_path.map_or(day2::INPUT, Source::from_arg);
+    let input = source.read().map_err(|error| error.to_string())?;
+    let ids = day2::Day2::parse(&input).map_err(|error| diagnostic::render(&error, &source.to_string(), &input))?;
+
+    print!("{}", inventory::render(&ids, distance));
+    return Ok(());
+}
+
+fn main() {
+    let command = Command::from_args(args().skip(1)).unwrap_or_else(|message| {
+        eprintln!("{}", message);
+        eprintln!("{}", USAGE);
+        exit(2);
+    });
+
+    let result = match command {
+        Command::Run {
+            day,
+            part,
+            solver,
+            input_path,
+            timings,
+            format,
+            render_path,
+        } => run(
+            day,
+            part,
+            solver.as_deref(),
+            input_path.as_deref(),
+            timings,
+            format,
+            render_path.as_deref(),
+        ),
+        Command::Verify {
+            answers_path,
+            record,
+        } => verify(answers_path.as_deref(), record),
+        Command::Calibrate { interval } => calibrate::calibrate(stdin().lock(), stdout().lock(), interval),
+        Command::Inventory {
+            distance,
+            input_path,
+        } => inventory(distance, input_path.as_deref()),
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message.trim_end());
+        exit(1);
+    }
+}
@@ -0,0 +1,51 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use day3::render::heat_map;
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum ImageFormat {
+    Png,
+    Ppm,
+}
+
+impl ImageFormat {
+    /// The format to write, going by the extension of `path`.
+    pub fn from_path(path: &str) -> Result<ImageFormat, String> {
+        return match Path::new(path).extension().and_then(|extension| extension.to_str()) {
+            Some("png") => Ok(ImageFormat::Png),
+            Some("ppm") => Ok(ImageFormat::Ppm),
+            _ => Err(format!("Cannot render to {}: expected a .png or .ppm file", path)),
+        };
+    }
+}
+
+/// Writes a heat map of the day 3 claims to `path`.
+pub fn render(claims: &[day3::Claim], path: &str) -> Result<(), String> {
+    let format = ImageFormat::from_path(path)?;
+    let image = heat_map(&day3::build_fabric_sheet(claims)).map_err(|error| format!("Cannot render to {}: {}", path, error))?;
+
+    let file = File::create(path).map_err(|error| format!("Failed to create {}: {}", path, error))?;
+    let output = BufWriter::new(file);
+    return match format {
+        ImageFormat::Png => image.write_png(output),
+        ImageFormat::Ppm => image.write_ppm(output),
+    }
+    .map_err(|error| format!("Failed to write {}: {}", path, error));
+}
+
+#[cfg(test)]
+mod test {
+    use super::ImageFormat;
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(ImageFormat::from_path("out.png"), Ok(ImageFormat::Png));
+        assert_eq!(ImageFormat::from_path("renders/day3.ppm"), Ok(ImageFormat::Ppm));
+        assert_eq!(
+            ImageFormat::from_path("out.jpg"),
+            Err("Cannot render to out.jpg: expected a .png or .ppm file".to_string())
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::alloc;
+use crate::duration::format_duration;
+
+pub struct Timing {
+    pub phase: &'static str,
+    pub elapsed: Duration,
+    pub peak_bytes: usize,
+}
+
+/// Runs `phase`, measuring its wall time and the most memory it had
+/// allocated at once on top of what was already allocated.
+pub fn measure<T, F: FnOnce() -> T>(phase: &'static str, run: F) -> (T, Timing) {
+    let baseline = alloc::reset_peak();
+    let start = Instant::now();
+
+    let result = run();
+
+    let timing = Timing {
+        phase,
+        elapsed: start.elapsed(),
+        peak_bytes: alloc::peak().saturating_sub(baseline),
+    };
+
+    return (result, timing);
+}
+
+fn format_bytes(bytes: usize) -> String {
+    let units = [("GiB", 1 << 30), ("MiB", 1 << 20), ("KiB", 1 << 10)];
+
+    return units
+        .iter()
+        .find(|&&(_, scale)| bytes >= scale)
+        .map(|&(unit, scale)| format!("{:.1} {}", bytes as f64 / scale as f64, unit))
+        .unwrap_or_else(|| format!("{} B", bytes));
+}
+
+pub fn render(timings: &[Timing]) -> String {
+    let mut output = format!("{:<8}{:>12}{:>14}\n", "phase", "time", "peak memory");
+
+    for timing in timings {
+        output += &format!(
+            "{:<8}{:>12}{:>14}\n",
+            timing.phase,
+            format_duration(timing.elapsed),
+            format_bytes(timing.peak_bytes)
+        );
+    }
+
+    return output;
+}
+
+#[cfg(test)]
+mod test {
+    use super::format_bytes;
+    use super::format_duration;
+    use super::render;
+    use super::Timing;
+    use std::time::Duration;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512 ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.50 ms");
+        assert_eq!(format_duration(Duration::from_secs(16)), "16.00 s");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(100), "100 B");
+        assert_eq!(format_bytes(4_000_000), "3.8 MiB");
+    }
+
+    #[test]
+    fn test_render() {
+        let timings = [
+            Timing { phase: "parse", elapsed: Duration::from_micros(30), peak_bytes: 2048 },
+            Timing { phase: "part 1", elapsed: Duration::from_millis(45), peak_bytes: 4_000_000 },
+        ];
+
+        let expected = [
+            "phase           time   peak memory",
+            "parse       30.00 µs       2.0 KiB",
+            "part 1      45.00 ms       3.8 MiB",
+            "",
+        ];
+
+        assert_eq!(render(&timings), expected.join("\n"));
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+
+use crate::answers::input_hash;
+use crate::answers::Answers;
+use crate::days::DAYS;
+
+#[derive(PartialEq, Debug)]
+pub enum Status {
+    Pass,
+    Regressed { expected: String },
+    Failed(String),
+    Unknown,
+}
+
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: Option<String>,
+    pub status: Status,
+}
+
+fn status(expected: Option<&str>, answer: &Result<String, String>) -> Status {
+    return match (expected, answer) {
+        (_, Err(error)) => Status::Failed(error.clone()),
+        (None, Ok(_)) => Status::Unknown,
+        (Some(expected), Ok(answer)) if expected == answer => Status::Pass,
+        (Some(expected), Ok(_)) => Status::Regressed {
+            expected: expected.to_string(),
+        },
+    };
+}
+
+/// Runs every part of every day against its bundled input and compares the
+/// answers with the known ones.
+pub fn verify(answers: &Answers) -> Vec<Outcome> {
+    let mut outcomes = Vec::new();
+
+    for day in DAYS {
+        let input = day.input.read().map_err(|error| error.to_string());
+        let hash = input.as_ref().map(|input| input_hash(input)).unwrap_or_default();
+
+        for part in 1..=2 {
+            let answer = input.clone().and_then(|input| {
+                (day.solve)(part, &input).map_err(|error| error.to_string())
+            });
+
+            outcomes.push(Outcome {
+                day: day.number,
+                part,
+                status: status(answers.get(day.number, part, &hash), &answer),
+                input_hash: hash.clone(),
+                answer: answer.ok(),
+            });
+        }
+    }
+
+    return outcomes;
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "day {} part {}: ", self.day, self.part)?;
+
+        match (&self.status, &self.answer) {
+            (Status::Pass, Some(answer)) => write!(f, "pass ({})", answer),
+            (Status::Regressed { expected }, Some(answer)) => {
+                write!(f, "regressed (expected {}, got {})", expected, answer)
+            }
+            (Status::Unknown, Some(answer)) => write!(f, "unknown ({})", answer),
+            (Status::Failed(error), _) => write!(f, "fail ({})", error),
+            (_, None) => write!(f, "fail"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::status;
+    use super::Status;
+
+    #[test]
+    fn test_status() {
+        assert_eq!(status(Some("459"), &Ok("459".to_string())), Status::Pass);
+        assert_eq!(status(None, &Ok("459".to_string())), Status::Unknown);
+        assert_eq!(
+            status(Some("459"), &Ok("460".to_string())),
+            Status::Regressed {
+                expected: "459".to_string()
+            }
+        );
+        assert_eq!(
+            status(Some("459"), &Err("No matches found".to_string())),
+            Status::Failed("No matches found".to_string())
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use serde::Deserialize;
+use solution::solve;
+use solution::Error;
+use std::collections::BTreeMap;
+use std::fs;
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../fixtures");
+
+#[derive(Deserialize)]
+struct Example {
+    input: String,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+fn examples(day: &str) -> Vec<Example> {
+    let content = fs::read_to_string(format!("{}/expected.toml", FIXTURES)).unwrap();
+    let mut examples: BTreeMap<String, Vec<Example>> = toml::from_str(&content).unwrap();
+
+    return examples.remove(day).expect("No examples for day");
+}
+
+fn check_examples(day: &str, solve: fn(u8, &str) -> Result<String, Error>) {
+    for example in examples(day) {
+        let input = fs::read_to_string(format!("{}/{}/{}", FIXTURES, day, example.input)).unwrap();
+
+        for (part, expected) in [(1, &example.part1), (2, &example.part2)] {
+            if let Some(expected) = expected {
+                let answer = solve(part, &input);
+                assert_eq!(answer.as_ref(), Ok(expected), "{} {} part {}", day, example.input, part);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_day1_examples() {
+    check_examples("day1", solve::<day1::Day1>);
+    check_examples("day1", solve::<day1::Day1ByResidue>);
+    check_examples("day1", solve::<day1::Day1<i128>>);
+    check_examples("day1", solve::<day1::Day1ByResidue<i128>>);
+    check_examples("day1", solve::<day1::Day1<day1::BigInt>>);
+    check_examples("day1", solve::<day1::Day1ByResidue<day1::BigInt>>);
+}
+
+#[test]
+fn test_day2_examples() {
+    check_examples("day2", solve::<day2::Day2>);
+}
+
+#[test]
+fn test_day3_examples() {
+    check_examples("day3", solve::<day3::Day3>);
+    check_examples("day3", solve::<day3::Day3Sweep>);
+}
+
+#[test]
+fn test_day4_examples() {
+    check_examples("day4", solve::<day4::Day4>);
+}
+
+#[test]
+fn test_day5_examples() {
+    check_examples("day5", solve::<day5::Day5>);
+}
+
+#[test]
+fn test_day6_examples() {
+    check_examples("day6", solve::<day6::Day6>);
+}
+
+#[test]
+fn test_day7_examples() {
+    check_examples("day7", solve::<day7::Day7>);
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use crate::frequency::Frequency;
+use crate::frequency::FrequencyError;
+
+/// Tracks the frequency as changes arrive one at a time, for input that is
+/// too long, or never ends, to collect up front. Frequencies are only
+/// remembered until the first repeat has been found.
+pub struct Calibrator<T> {
+    frequency: T,
+    applied: usize,
+    seen: HashSet<T>,
+    first_repeat: Option<(T, usize)>,
+}
+
+impl<T: Frequency> Calibrator<T> {
+    pub fn new() -> Calibrator<T> {
+        let mut seen = HashSet::new();
+        seen.insert(T::zero());
+        return Calibrator {
+            frequency: T::zero(),
+            applied: 0,
+            seen,
+            first_repeat: None,
+        };
+    }
+
+    /// Applies `change`, returning true if it reached a frequency for the
+    /// second time and no other change had before it.
+    pub fn apply(&mut self, change: &T) -> Result<bool, FrequencyError<T>> {
+        self.frequency = self.frequency.checked_add(change).ok_or(FrequencyError::Overflow)?;
+        self.applied += 1;
+
+        if self.first_repeat.is_some() || self.seen.insert(self.frequency.clone()) {
+            return Ok(false);
+        }
+        self.first_repeat = Some((self.frequency.clone(), self.applied));
+        self.seen = HashSet::new();
+        return Ok(true);
+    }
+
+    pub fn frequency(&self) -> &T {
+        return &self.frequency;
+    }
+
+    /// How many changes have been applied so far.
+    pub fn applied(&self) -> usize {
+        return self.applied;
+    }
+
+    /// The first frequency reached twice, and how many changes it took.
+    pub fn first_repeat(&self) -> Option<&(T, usize)> {
+        return self.first_repeat.as_ref();
+    }
+}
+
+impl<T: Frequency> Default for Calibrator<T> {
+    fn default() -> Calibrator<T> {
+        return Calibrator::new();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Calibrator;
+    use crate::frequency::FrequencyError;
+
+    #[test]
+    fn test_running_total() {
+        let mut calibrator: Calibrator<i64> = Calibrator::new();
+        for change in [1, -2, 3].iter() {
+            assert_eq!(calibrator.apply(change), Ok(false));
+        }
+        assert_eq!(*calibrator.frequency(), 2);
+        assert_eq!(calibrator.applied(), 3);
+        assert_eq!(calibrator.first_repeat(), None);
+    }
+
+    #[test]
+    fn test_first_repeat_reported_once() {
+        let mut calibrator: Calibrator<i64> = Calibrator::new();
+        let reported: Vec<bool> = [3, 3, 4, -2, -4, 3, 3]
+            .iter()
+            .map(|change| calibrator.apply(change).unwrap())
+            .collect();
+
+        assert_eq!(reported, vec![false, false, false, false, false, false, true]);
+        assert_eq!(calibrator.first_repeat(), Some(&(10, 7)));
+        assert_eq!(calibrator.apply(&-10), Ok(false));
+        assert_eq!(*calibrator.frequency(), 0);
+    }
+
+    #[test]
+    fn test_repeat_of_start() {
+        let mut calibrator: Calibrator<i32> = Calibrator::new();
+        assert_eq!(calibrator.apply(&5), Ok(false));
+        assert_eq!(calibrator.apply(&-5), Ok(true));
+        assert_eq!(calibrator.first_repeat(), Some(&(0, 2)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut calibrator: Calibrator<i32> = Calibrator::new();
+        assert_eq!(calibrator.apply(&i32::MAX), Ok(false));
+        assert_eq!(calibrator.apply(&1), Err(FrequencyError::Overflow));
+    }
+}
@@ -0,0 +1,368 @@
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::Hash;
+use std::iter::once;
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+
+/// An integer type frequencies can be tracked in. Every operation is
+/// checked, returning `None` where the type would overflow.
+pub trait Frequency: Clone + Eq + Hash + Ord + fmt::Debug + fmt::Display + FromStr {
+    fn zero() -> Self;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+    fn checked_rem_euclid(&self, other: &Self) -> Option<Self>;
+    fn to_usize(&self) -> Option<usize>;
+}
+
+macro_rules! primitive_frequency {
+    ($($type:ty),*) => {
+        $(impl Frequency for $type {
+            fn zero() -> $type {
+                return 0;
+            }
+
+            fn checked_add(&self, other: &$type) -> Option<$type> {
+                return <$type>::checked_add(*self, *other);
+            }
+
+            fn checked_sub(&self, other: &$type) -> Option<$type> {
+                return <$type>::checked_sub(*self, *other);
+            }
+
+            fn checked_div(&self, other: &$type) -> Option<$type> {
+                return <$type>::checked_div(*self, *other);
+            }
+
+            fn checked_rem_euclid(&self, other: &$type) -> Option<$type> {
+                return <$type>::checked_rem_euclid(*self, *other);
+            }
+
+            fn to_usize(&self) -> Option<usize> {
+                return usize::try_from(*self).ok();
+            }
+        })*
+    };
+}
+
+primitive_frequency!(i32, i64, i128);
+
+impl Frequency for BigInt {
+    fn zero() -> BigInt {
+        return Zero::zero();
+    }
+
+    fn checked_add(&self, other: &BigInt) -> Option<BigInt> {
+        return Some(self + other);
+    }
+
+    fn checked_sub(&self, other: &BigInt) -> Option<BigInt> {
+        return Some(self - other);
+    }
+
+    fn checked_div(&self, other: &BigInt) -> Option<BigInt> {
+        return BigInt::checked_div(self, other);
+    }
+
+    fn checked_rem_euclid(&self, other: &BigInt) -> Option<BigInt> {
+        if other.is_zero() {
+            return None;
+        }
+        let remainder = self % other;
+        if remainder.is_negative() {
+            return Some(remainder + other.abs());
+        }
+        return Some(remainder);
+    }
+
+    fn to_usize(&self) -> Option<usize> {
+        return ToPrimitive::to_usize(self);
+    }
+}
+
+/// The first frequency reached twice, and where in the list of changes it
+/// was reached. `pass` counts completed passes over the list, so a repeat
+/// during the first pass has `pass == 0`; `index` is the position of the
+/// change that produced it.
+#[derive(PartialEq, Debug)]
+pub struct Repeat<T> {
+    pub frequency: T,
+    pub pass: usize,
+    pub index: usize,
+}
+
+#[derive(PartialEq, Debug)]
+pub enum FrequencyError<T> {
+    NoChanges,
+    NoRepeat { drift: T },
+    Overflow,
+}
+
+impl<T> FrequencyError<T> {
+    pub fn message(&self) -> &'static str {
+        return match self {
+            FrequencyError::NoChanges => "No frequency changes to apply",
+            FrequencyError::NoRepeat { .. } => "No frequency is ever reached twice",
+            FrequencyError::Overflow => "Frequency overflowed, try a wider integer type",
+        };
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for FrequencyError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            FrequencyError::NoRepeat { drift } => {
+                write!(f, "{} (drift per pass is {:+})", self.message(), drift)
+            }
+            _ => write!(f, "{}", self.message()),
+        };
+    }
+}
+
+/// The frequency after each change of a single pass, starting from zero.
+pub fn prefix_sums<T: Frequency>(changes: &[T]) -> Result<Vec<T>, FrequencyError<T>> {
+    let mut frequency = T::zero();
+    let mut sums = Vec::with_capacity(changes.len());
+    for change in changes {
+        frequency = frequency.checked_add(change).ok_or(FrequencyError::Overflow)?;
+        sums.push(frequency.clone());
+    }
+    return Ok(sums);
+}
+
+/// The net change in frequency over one full pass of the list.
+pub fn drift<T: Frequency>(changes: &[T]) -> Result<T, FrequencyError<T>> {
+    return Ok(prefix_sums(changes)?.pop().unwrap_or_else(T::zero));
+}
+
+/// Every pass shifts the frequencies of the first by the drift, so two of
+/// them can only ever meet if they share a residue modulo the drift. With
+/// no drift the last change returns to the starting frequency, which is a
+/// repeat on its own.
+fn can_repeat<T: Frequency>(sums: &[T], drift: &T) -> Result<bool, FrequencyError<T>> {
+    if *drift == T::zero() {
+        return Ok(true);
+    }
+    let mut residues: HashSet<T> = HashSet::new();
+    for sum in sums {
+        let residue = sum.checked_rem_euclid(drift).ok_or(FrequencyError::Overflow)?;
+        if !residues.insert(residue) {
+            return Ok(true);
+        }
+    }
+    return Ok(false);
+}
+
+/// Finds the first repeat by applying the changes over and over, remembering
+/// every frequency reached.
+pub fn find_first_repeat<T: Frequency>(changes: &[T]) -> Result<Repeat<T>, FrequencyError<T>> {
+    if changes.is_empty() {
+        return Err(FrequencyError::NoChanges);
+    }
+    let sums = prefix_sums(changes)?;
+    let drift = sums[sums.len() - 1].clone();
+    if !can_repeat(&sums, &drift)? {
+        return Err(FrequencyError::NoRepeat { drift });
+    }
+
+    let mut frequency = T::zero();
+    let mut seen: HashSet<T> = HashSet::new();
+    seen.insert(frequency.clone());
+    for pass in 0.. {
+        for (index, change) in changes.iter().enumerate() {
+            frequency = frequency.checked_add(change).ok_or(FrequencyError::Overflow)?;
+            if !seen.insert(frequency.clone()) {
+                return Ok(Repeat { frequency, pass, index });
+            }
+        }
+    }
+    unreachable!();
+}
+
+/// Finds the first repeat from the prefix sums of a single pass. A frequency
+/// only ever moves forward through its residue class, one drift per pass, so
+/// it first repeats when it catches up with the nearest frequency ahead of
+/// it. Sorting each class finds all of those in O(n log n).
+pub fn find_first_repeat_by_residue<T: Frequency>(changes: &[T]) -> Result<Repeat<T>, FrequencyError<T>> {
+    if changes.is_empty() {
+        return Err(FrequencyError::NoChanges);
+    }
+
+    let sums = prefix_sums(changes)?;
+    let mut seen: HashSet<&T> = HashSet::new();
+    let zero = T::zero();
+    seen.insert(&zero);
+    for (index, frequency) in sums.iter().enumerate() {
+        if !seen.insert(frequency) {
+            return Ok(Repeat {
+                frequency: frequency.clone(),
+                pass: 0,
+                index,
+            });
+        }
+    }
+
+    // A drift of zero brings the last change back to the start, which the
+    // first pass already caught.
+    let drift = sums[sums.len() - 1].clone();
+    let forward = drift > T::zero();
+
+    // The starting frequency can be caught up with, but never moves itself.
+    let mut positions: Vec<(T, T, Option<usize>)> = Vec::with_capacity(sums.len() + 1);
+    for (frequency, index) in once((T::zero(), None)).chain(sums.into_iter().enumerate().map(|(index, sum)| (sum, Some(index)))) {
+        let residue = frequency.checked_rem_euclid(&drift).ok_or(FrequencyError::Overflow)?;
+        positions.push((residue, frequency, index));
+    }
+    positions.sort_unstable();
+
+    // Passes are compared in `T`, since only the earliest has to fit a usize.
+    let mut first: Option<(T, usize, &T)> = None;
+    for pair in positions.windows(2).filter(|pair| pair[0].0 == pair[1].0) {
+        let (behind, ahead) = if forward { (&pair[0], &pair[1]) } else { (&pair[1], &pair[0]) };
+        let index = match behind.2 {
+            Some(index) => index,
+            None => continue,
+        };
+        let passes = ahead
+            .1
+            .checked_sub(&behind.1)
+            .and_then(|gap| gap.checked_div(&drift))
+            .ok_or(FrequencyError::Overflow)?;
+
+        if first.as_ref().is_none_or(|(first_passes, first_index, _)| (&passes, index) < (first_passes, *first_index)) {
+            first = Some((passes, index, &ahead.1));
+        }
+    }
+
+    return match first {
+        Some((passes, index, frequency)) => Ok(Repeat {
+            frequency: frequency.clone(),
+            pass: passes.to_usize().ok_or(FrequencyError::Overflow)?,
+            index,
+        }),
+        None => Err(FrequencyError::NoRepeat { drift }),
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::find_first_repeat;
+    use super::find_first_repeat_by_residue;
+    use super::prefix_sums;
+    use super::Frequency;
+    use super::FrequencyError;
+    use super::Repeat;
+    use num_bigint::BigInt;
+
+    #[test]
+    fn test_prefix_sums() {
+        assert_eq!(prefix_sums(&[1, -2, 3, 1]), Ok(vec![1, -1, 2, 3]));
+    }
+
+    #[test]
+    fn test_repeat_in_first_pass() {
+        assert_eq!(
+            find_first_repeat(&[1, -1]),
+            Ok(Repeat { frequency: 0, pass: 0, index: 1 })
+        );
+    }
+
+    #[test]
+    fn test_repeat_in_later_pass() {
+        assert_eq!(
+            find_first_repeat(&[1, -2, 3, 1]),
+            Ok(Repeat { frequency: 2, pass: 1, index: 1 })
+        );
+        assert_eq!(
+            find_first_repeat(&[7, 7, -2, -7, -4]),
+            Ok(Repeat { frequency: 14, pass: 2, index: 2 })
+        );
+    }
+
+    #[test]
+    fn test_no_repeat() {
+        assert_eq!(find_first_repeat(&[1, 1]), Err(FrequencyError::NoRepeat { drift: 2 }));
+        assert_eq!(
+            find_first_repeat(&[-1, -2, -3]),
+            Err(FrequencyError::NoRepeat { drift: -6 })
+        );
+    }
+
+    #[test]
+    fn test_no_changes() {
+        assert_eq!(find_first_repeat::<i32>(&[]), Err(FrequencyError::NoChanges));
+    }
+
+    #[test]
+    fn test_by_residue_matches_simulation() {
+        let cases: [&[i32]; 7] = [
+            &[1, -1],
+            &[3, 3, 4, -2, -4],
+            &[-6, 3, 8, 5, -6],
+            &[7, 7, -2, -7, -4],
+            &[1, -2, 3, 1],
+            &[-3, 1, 4],
+            &[5, -9, 2, 2, 2],
+        ];
+        for changes in cases.iter() {
+            assert_eq!(find_first_repeat_by_residue(changes), find_first_repeat(changes));
+        }
+    }
+
+    #[test]
+    fn test_by_residue_no_repeat() {
+        assert_eq!(
+            find_first_repeat_by_residue(&[1, 1]),
+            Err(FrequencyError::NoRepeat { drift: 2 })
+        );
+        assert_eq!(find_first_repeat_by_residue::<i32>(&[]), Err(FrequencyError::NoChanges));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(prefix_sums(&[i32::MAX, 1]), Err(FrequencyError::Overflow));
+        assert_eq!(find_first_repeat(&[i64::MAX, 1]), Err(FrequencyError::Overflow));
+        assert_eq!(find_first_repeat_by_residue(&[i64::MIN, -1]), Err(FrequencyError::Overflow));
+    }
+
+    #[test]
+    fn test_wider_types() {
+        let changes: Vec<i128> = vec![3, 2 * i128::from(i64::MAX), -3];
+        let expected = Repeat {
+            frequency: 2 * i128::from(i64::MAX) + 3,
+            pass: 1,
+            index: 0,
+        };
+        assert_eq!(find_first_repeat(&changes), Ok(expected));
+        assert_eq!(find_first_repeat_by_residue(&changes), find_first_repeat(&changes));
+    }
+
+    #[test]
+    fn test_big_remainders_match_primitives() {
+        for &(a, b) in [(-7i64, 2i64), (7, -2), (-7, -2), (6, 3)].iter() {
+            let big = BigInt::from(a).checked_rem_euclid(&BigInt::from(b));
+            assert_eq!(big, Frequency::checked_rem_euclid(&a, &b).map(BigInt::from));
+        }
+        assert_eq!(BigInt::from(1).checked_rem_euclid(&BigInt::from(0)), None);
+        assert_eq!(Frequency::checked_div(&BigInt::from(1), &BigInt::from(0)), None);
+    }
+
+    #[test]
+    fn test_big_integers() {
+        let huge: BigInt = "1000000000000000000000000000000000000000".parse().unwrap();
+        let changes = vec![huge.clone(), -&huge + 1, BigInt::from(-3), BigInt::from(5)];
+        let expected = Repeat {
+            frequency: BigInt::from(1),
+            pass: 1,
+            index: 2,
+        };
+        assert_eq!(find_first_repeat(&changes), Ok(expected));
+        assert_eq!(find_first_repeat_by_residue(&changes), find_first_repeat(&changes));
+    }
+}
@@ -0,0 +1,78 @@
+pub mod calibrator;
+pub mod frequency;
+pub mod parse;
+
+use puzzle_input::Source;
+use solution::ParseError;
+use solution::Solution;
+use std::marker::PhantomData;
+use std::vec::Vec;
+
+use crate::frequency::Frequency;
+
+pub use num_bigint::BigInt;
+
+pub const INPUT: Source<'static> = puzzle_input::crate_input!();
+
+/// Day 1 with frequencies tracked in `T`, so inputs that outgrow `i64` can
+/// be solved as `Day1<i128>` or `Day1<BigInt>`.
+pub struct Day1<T = i64>(PhantomData<T>);
+
+impl<T: Frequency> Solution for Day1<T> {
+    type Input = Vec<T>;
+    type PartOne = T;
+    type PartTwo = T;
+
+    fn parse(input: &str) -> Result<Vec<T>, ParseError> {
+        return parse::parse_changes(input);
+    }
+
+    fn part1(numbers: &Vec<T>) -> Result<T, &'static str> {
+        return frequency::drift(numbers).map_err(|error| error.message());
+    }
+
+    fn part2(numbers: &Vec<T>) -> Result<T, &'static str> {
+        return frequency::find_first_repeat(numbers)
+            .map(|repeat| repeat.frequency)
+            .map_err(|error| error.message());
+    }
+}
+
+/// Day 1 with part two solved from the residues of the prefix sums rather
+/// than by simulating every pass.
+pub struct Day1ByResidue<T = i64>(PhantomData<T>);
+
+impl<T: Frequency> Solution for Day1ByResidue<T> {
+    type Input = Vec<T>;
+    type PartOne = T;
+    type PartTwo = T;
+
+    fn parse(input: &str) -> Result<Vec<T>, ParseError> {
+        return Day1::<T>::parse(input);
+    }
+
+    fn part1(numbers: &Vec<T>) -> Result<T, &'static str> {
+        return Day1::<T>::part1(numbers);
+    }
+
+    fn part2(numbers: &Vec<T>) -> Result<T, &'static str> {
+        return frequency::find_first_repeat_by_residue(numbers)
+            .map(|repeat| repeat.frequency)
+            .map_err(|error| error.message());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day1;
+    use super::Day1ByResidue;
+    use solution::Solution;
+
+    #[test]
+    fn test_start_counts_as_reached() {
+        let changes: Vec<i64> = vec![1, -1];
+
+        assert_eq!(Day1::part2(&changes), Ok(0));
+        assert_eq!(Day1ByResidue::part2(&changes), Ok(0));
+    }
+}
@@ -0,0 +1,75 @@
+use solution::column;
+use solution::parse_number;
+use solution::ParseError;
+use std::iter::once;
+
+use crate::frequency::Frequency;
+
+const CHANGE_HINT: &str = "expected changes like `+7` or `-3`, separated by commas, spaces or newlines";
+
+fn is_separator(c: char) -> bool {
+    return c == ',' || c.is_whitespace();
+}
+
+/// Parses the changes on a single line. Changes may be separated by commas
+/// or whitespace, the `+` sign is optional, and a `#` starts a comment.
+pub fn parse_line<T: Frequency>(line: &str) -> Result<Vec<T>, ParseError> {
+    let content = line.split('#').next().unwrap_or("");
+    let mut changes = Vec::new();
+    let mut start = None;
+
+    for (offset, c) in content.char_indices().chain(once((content.len(), ','))) {
+        match (start, is_separator(c)) {
+            (None, false) => start = Some(offset),
+            (Some(begin), true) => {
+                let change = parse_number(&content[begin..offset], column(line, begin))
+                    .map_err(|error| error.with_hint(CHANGE_HINT))?;
+                changes.push(change);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    return Ok(changes);
+}
+
+pub fn parse_changes<T: Frequency>(input: &str) -> Result<Vec<T>, ParseError> {
+    let mut changes = Vec::new();
+    for (number, line) in input.lines().enumerate() {
+        changes.extend(parse_line(line).map_err(|error| error.at_line(number + 1))?);
+    }
+    return Ok(changes);
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse_changes;
+    use solution::ParseErrorKind;
+
+    #[test]
+    fn test_one_per_line() {
+        assert_eq!(parse_changes::<i64>("+1\n-2\n+3\n+1\n\n"), Ok(vec![1, -2, 3, 1]));
+    }
+
+    #[test]
+    fn test_separators() {
+        assert_eq!(parse_changes::<i64>("+1, -2, +3, +1"), Ok(vec![1, -2, 3, 1]));
+        assert_eq!(parse_changes::<i64>("1 -2\t3,,1\r\n"), Ok(vec![1, -2, 3, 1]));
+    }
+
+    #[test]
+    fn test_comments() {
+        let input = "# calibration run\n+7, -3 # drift\n\n+2 #+5\n";
+        assert_eq!(parse_changes::<i64>(input), Ok(vec![7, -3, 2]));
+    }
+
+    #[test]
+    fn test_error_points_at_token() {
+        let error = parse_changes::<i64>("+1, -2\n+3, +x1, +1").unwrap_err();
+
+        assert_eq!(error.kind, ParseErrorKind::InvalidNumber);
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.text, "+x1");
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+
+use crate::box_id::BoxId;
+
+/// A distance between box IDs, counted in characters.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Distance {
+    /// Positions that differ, with every character past the end of the
+    /// shorter ID counting as a difference. This keeps it a metric over
+    /// IDs of any length.
+    Hamming,
+    /// The fewest insertions, deletions and substitutions between the IDs.
+    Levenshtein,
+}
+
+impl Distance {
+    pub fn between(self, a: &BoxId, b: &BoxId) -> usize {
+        return match self {
+            Distance::Hamming => hamming(a, b),
+            Distance::Levenshtein => levenshtein(a, b),
+        };
+    }
+}
+
+pub fn hamming(a: &BoxId, b: &BoxId) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mismatches = a.iter().zip(b).filter(|(x, y)| x != y).count();
+    return mismatches + a.len().max(b.len()) - a.len().min(b.len());
+}
+
+pub fn levenshtein(a: &BoxId, b: &BoxId) -> usize {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current: Vec<usize> = vec![0; b.len() + 1];
+
+    for (i, &x) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, &y) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(x != y);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    return previous[b.len()];
+}
+
+struct Node {
+    id: BoxId,
+    /// Child node index by its distance from this node.
+    children: HashMap<usize, usize>,
+}
+
+/// A Burkhard-Keller tree of box IDs. Each child sits at a known distance
+/// from its parent, so the triangle inequality rules out whole subtrees
+/// when searching for IDs near a query.
+pub struct BkTree {
+    distance: Distance,
+    nodes: Vec<Node>,
+}
+
+impl BkTree {
+    pub fn new(distance: Distance) -> BkTree {
+        return BkTree {
+            distance,
+            nodes: Vec::new(),
+        };
+    }
+
+    pub fn from_ids(distance: Distance, ids: &[BoxId]) -> BkTree {
+        let mut tree = BkTree::new(distance);
+        for id in ids {
+            tree.insert(id);
+        }
+        return tree;
+    }
+
+    /// Adds `id` to the tree, returning false if it was already there.
+    pub fn insert(&mut self, id: &BoxId) -> bool {
+        if self.nodes.is_empty() {
+            self.push(id);
+            return true;
+        }
+
+        let mut current = 0;
+        loop {
+            let distance = self.distance.between(id, &self.nodes[current].id);
+            if distance == 0 {
+                return false;
+            }
+            match self.nodes[current].children.get(&distance) {
+                Some(&child) => current = child,
+                None => {
+                    let child = self.push(id);
+                    self.nodes[current].children.insert(distance, child);
+                    return true;
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, id: &BoxId) -> usize {
+        self.nodes.push(Node {
+            id: id.clone(),
+            children: HashMap::new(),
+        });
+        return self.nodes.len() - 1;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.nodes.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.nodes.is_empty();
+    }
+
+    /// Every ID within `max_distance` of `query`, with its distance, closest
+    /// first and alphabetically among equals.
+    pub fn within(&self, query: &BoxId, max_distance: usize) -> Vec<(&BoxId, usize)> {
+        let mut found = Vec::new();
+        let mut pending = if self.nodes.is_empty() { vec![] } else { vec![0] };
+
+        while let Some(current) = pending.pop() {
+            let node = &self.nodes[current];
+            let distance = self.distance.between(query, &node.id);
+            if distance <= max_distance {
+                found.push((&node.id, distance));
+            }
+
+            let lowest = distance.saturating_sub(max_distance);
+            let highest = distance + max_distance;
+            pending.extend(
+                node.children
+                    .iter()
+                    .filter(|(&edge, _)| lowest <= edge && edge <= highest)
+                    .map(|(_, &child)| child),
+            );
+        }
+
+        found.sort_unstable_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(b.0)));
+        return found;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::hamming;
+    use super::levenshtein;
+    use super::BkTree;
+    use super::Distance;
+    use crate::box_id::BoxId;
+    use crate::testing::id;
+    use crate::testing::ids;
+
+    #[test]
+    fn test_hamming() {
+        assert_eq!(hamming(&id("abcde"), &id("axcye")), 2);
+        assert_eq!(hamming(&id("abc"), &id("abcde")), 2);
+        assert_eq!(hamming(&id("abcde"), &id("ab")), 3);
+        assert_eq!(hamming(&id("abc"), &id("abc")), 0);
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein(&id("kitten"), &id("sitting")), 3);
+        assert_eq!(levenshtein(&id("abcde"), &id("abde")), 1);
+        assert_eq!(levenshtein(&id("a"), &id("abcd")), 3);
+        assert_eq!(levenshtein(&id("fghij"), &id("fguij")), 1);
+    }
+
+    #[test]
+    fn test_insert_skips_duplicates() {
+        let mut tree = BkTree::new(Distance::Hamming);
+        assert!(tree.insert(&id("abc")));
+        assert!(tree.insert(&id("abd")));
+        assert!(!tree.insert(&id("abc")));
+        assert_eq!(tree.len(), 2);
+    }
+
+    #[test]
+    fn test_within_matches_brute_force() {
+        let queries = ids(&["fghij", "abcd", "abcde", "zzzzz", "q"]);
+        let ids = ids(&["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz", "abde", "fgij", "abcdef", "a"]);
+
+        for &distance in [Distance::Hamming, Distance::Levenshtein].iter() {
+            let tree = BkTree::from_ids(distance, &ids);
+            for query in queries.iter() {
+                for k in 0..4 {
+                    let mut expected: Vec<(&BoxId, usize)> = ids
+                        .iter()
+                        .map(|id| (id, distance.between(query, id)))
+                        .filter(|&(_, d)| d <= k)
+                        .collect();
+                    expected.sort_unstable_by(|a, b| a.1.cmp(&b.1).then(a.0.cmp(b.0)));
+
+                    assert_eq!(tree.within(query, k), expected, "{:?} {} {}", distance, query, k);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_within_unequal_lengths() {
+        let tree = BkTree::from_ids(Distance::Levenshtein, &ids(&["abcde", "abde", "abcdef", "xyz"]));
+        let found: Vec<(&str, usize)> = tree
+            .within(&id("abcde"), 1)
+            .into_iter()
+            .map(|(id, distance)| (id.as_str(), distance))
+            .collect();
+        assert_eq!(found, vec![("abcde", 0), ("abcdef", 1), ("abde", 1)]);
+    }
+}
@@ -0,0 +1,83 @@
+use solution::column;
+use solution::ParseError;
+use solution::ParseErrorKind;
+use std::fmt;
+use std::str::FromStr;
+
+const BOX_ID_HINT: &str = "expected a box ID of printable ASCII characters, like `abcdef`";
+
+/// A box ID, checked when parsed to hold only printable ASCII, so that its
+/// bytes, its characters and the letters a reader sees all line up.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct BoxId(String);
+
+impl BoxId {
+    pub fn as_str(&self) -> &str {
+        return &self.0;
+    }
+
+    /// The ID's bytes, one per character since it is printable ASCII.
+    pub fn as_bytes(&self) -> &[u8] {
+        return self.0.as_bytes();
+    }
+}
+
+impl FromStr for BoxId {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<BoxId, ParseError> {
+        if line.is_empty() {
+            return Err(ParseError::new(ParseErrorKind::Expected("a box ID"), 1, "").with_hint(BOX_ID_HINT));
+        }
+
+        if let Some((offset, ch)) = line.char_indices().find(|&(_, ch)| !ch.is_ascii_graphic()) {
+            let error = ParseError::new(
+                ParseErrorKind::Expected("a printable ASCII character"),
+                column(line, offset),
+                &ch.to_string(),
+            );
+            return Err(error.with_hint(BOX_ID_HINT));
+        }
+
+        return Ok(BoxId(line.to_string()));
+    }
+}
+
+impl AsRef<str> for BoxId {
+    fn as_ref(&self) -> &str {
+        return &self.0;
+    }
+}
+
+impl fmt::Display for BoxId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return f.write_str(&self.0);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BoxId;
+    use solution::ParseErrorKind;
+
+    #[test]
+    fn test_parse_box_id() {
+        assert_eq!("abcdef".parse::<BoxId>().unwrap().as_str(), "abcdef");
+    }
+
+    #[test]
+    fn test_reject_multi_byte() {
+        let error = "abçdé".parse::<BoxId>().unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::Expected("a printable ASCII character"));
+        assert_eq!((error.column, error.text.as_str()), (3, "ç"));
+
+        let error = "ab\u{65}\u{301}".parse::<BoxId>().unwrap_err();
+        assert_eq!((error.column, error.text.as_str()), (4, "\u{301}"));
+    }
+
+    #[test]
+    fn test_reject_whitespace_and_empty() {
+        assert_eq!("ab cd".parse::<BoxId>().unwrap_err().column, 3);
+        assert_eq!("".parse::<BoxId>().unwrap_err().kind, ParseErrorKind::Expected("a box ID"));
+    }
+}
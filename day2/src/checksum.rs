@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+use std::collections::HashMap;
+use std::collections::BTreeMap;
+
+use crate::box_id::BoxId;
+
+/// The multiplicities the puzzle's checksum counts.
+pub const DEFAULT_MULTIPLICITIES: [usize; 2] = [2, 3];
+
+/// How many times each letter occurs in `id`, in a single pass.
+pub fn histogram(id: &BoxId) -> HashMap<char, usize> {
+    let mut histogram: HashMap<char, usize> = HashMap::new();
+    for &byte in id.as_bytes() {
+        *histogram.entry(char::from(byte)).or_insert(0) += 1;
+    }
+
+    return histogram;
+}
+
+/// The number of IDs with some letter occurring exactly `multiplicity`
+/// times, for each of the requested multiplicities, and their product.
+#[derive(PartialEq, Debug)]
+pub struct Checksum {
+    pub counts: BTreeMap<usize, usize>,
+    pub product: usize,
+}
+
+pub fn checksum_with(ids: &[BoxId], multiplicities: &[usize]) -> Checksum {
+    let counts: BTreeMap<usize, usize> = contributors(ids, multiplicities)
+        .into_iter()
+        .map(|(multiplicity, ids)| (multiplicity, ids.len()))
+        .collect();
+
+    let product = counts.values().product();
+    return Checksum { counts, product };
+}
+
+/// The IDs counted towards each of the multiplicities, in input order.
+pub fn contributors<'a>(ids: &'a [BoxId], multiplicities: &[usize]) -> BTreeMap<usize, Vec<&'a str>> {
+    let mut contributors: BTreeMap<usize, Vec<&str>> =
+        multiplicities.iter().map(|&multiplicity| (multiplicity, Vec::new())).collect();
+    for id in ids {
+        let occurances: HashSet<usize> = histogram(id).into_values().collect();
+        for (multiplicity, contributors) in contributors.iter_mut() {
+            if occurances.contains(multiplicity) {
+                contributors.push(id.as_str());
+            }
+        }
+    }
+
+    return contributors;
+}
+
+pub fn checksum(ids: &[BoxId]) -> Checksum {
+    return checksum_with(ids, &DEFAULT_MULTIPLICITIES);
+}
+
+#[cfg(test)]
+mod test {
+    use super::checksum;
+    use super::checksum_with;
+    use super::contributors;
+    use super::histogram;
+    use crate::testing::ids;
+    use std::vec::Vec;
+
+    #[test]
+    fn test_histogram() {
+        let result = histogram(&"bababc".parse().unwrap());
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[&'a'], 2);
+        assert_eq!(result[&'b'], 3);
+        assert_eq!(result[&'c'], 1);
+    }
+
+    #[test]
+    fn test_checksum() {
+        let result = checksum(&ids(&["abcdef", "bababc", "abbcde", "abcccd", "aabcdd", "abcdee", "ababab"]));
+        assert_eq!(result.counts.into_iter().collect::<Vec<_>>(), vec![(2, 4), (3, 3)]);
+        assert_eq!(result.product, 12);
+    }
+
+    #[test]
+    fn test_checksum_ignores_other_multiplicities() {
+        let result = checksum(&ids(&["aabbbb", "cccc", "ddd"]));
+        assert_eq!(result.counts.into_iter().collect::<Vec<_>>(), vec![(2, 1), (3, 1)]);
+        assert_eq!(result.product, 1);
+    }
+
+    #[test]
+    fn test_checksum_with_multiplicities() {
+        let result = checksum_with(&ids(&["aabbbb", "cccc", "ddd"]), &[4, 2, 4]);
+        assert_eq!(result.counts.into_iter().collect::<Vec<_>>(), vec![(2, 1), (4, 2)]);
+        assert_eq!(result.product, 2);
+    }
+
+    #[test]
+    fn test_checksum_without_repeats() {
+        assert_eq!(checksum(&ids(&["abc"])).product, 0);
+    }
+
+    #[test]
+    fn test_contributors_no_repeats() {
+        let ids = ids(&["abcdef"]);
+        let result = contributors(&ids, &[2, 3]);
+        assert!(result.values().all(|ids| ids.is_empty()));
+    }
+
+    #[test]
+    fn test_contributors_two_and_three() {
+        let ids = ids(&["bababc"]);
+        let result = contributors(&ids, &[2, 3]);
+        assert_eq!(result[&2], vec!["bababc"]);
+        assert_eq!(result[&3], vec!["bababc"]);
+    }
+
+    #[test]
+    fn test_checksum_of_two_ids() {
+        assert_eq!(checksum(&ids(&["bababc", "bababc"])).product, 4);
+    }
+
+    #[test]
+    fn test_contributors() {
+        let ids = ids(&["abcdef", "bababc", "abbcde", "abcccd", "aabcdd"]);
+        let result = contributors(&ids, &[2, 3]);
+        assert_eq!(result[&2], vec!["bababc", "abbcde", "aabcdd"]);
+        assert_eq!(result[&3], vec!["bababc", "abcccd"]);
+    }
+}
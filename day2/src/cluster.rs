@@ -0,0 +1,155 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use crate::bktree::BkTree;
+use crate::bktree::Distance;
+use crate::box_id::BoxId;
+
+/// Box IDs that are all within some Hamming distance of each other, in the
+/// order they appear in the input.
+#[derive(PartialEq, Debug)]
+pub struct Cluster<'a> {
+    pub ids: Vec<&'a str>,
+}
+
+impl<'a> Cluster<'a> {
+    /// The letters every ID in the cluster has in the same position.
+    pub fn common(&self) -> String {
+        let ids: Vec<Vec<char>> = self.ids.iter().map(|id| id.chars().collect()).collect();
+        let length = ids.iter().map(Vec::len).min().unwrap_or(0);
+
+        return (0..length)
+            .map(|position| ids[0][position])
+            .enumerate()
+            .filter(|&(position, ch)| ids.iter().all(|id| id[position] == ch))
+            .map(|(_, ch)| ch)
+            .collect();
+    }
+}
+
+/// Bron–Kerbosch with pivoting: extends the clique `clique` with the
+/// `candidates`, skipping any clique that would also fit one of `excluded`,
+/// so only maximal cliques are reported.
+fn extend_clique(
+    clique: &mut Vec<usize>,
+    mut candidates: BTreeSet<usize>,
+    mut excluded: BTreeSet<usize>,
+    neighbours: &[BTreeSet<usize>],
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    let pivot = candidates
+        .union(&excluded)
+        .max_by_key(|&&vertex| candidates.intersection(&neighbours[vertex]).count())
+        .copied();
+    let pivot = match pivot {
+        Some(pivot) => pivot,
+        None => {
+            cliques.push(clique.clone());
+            return;
+        }
+    };
+
+    let remaining: Vec<usize> = candidates.difference(&neighbours[pivot]).copied().collect();
+    for vertex in remaining {
+        clique.push(vertex);
+        extend_clique(
+            clique,
+            candidates.intersection(&neighbours[vertex]).copied().collect(),
+            excluded.intersection(&neighbours[vertex]).copied().collect(),
+            neighbours,
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&vertex);
+        excluded.insert(vertex);
+    }
+}
+
+/// Groups the IDs that are all within `max_distance` of one another, the
+/// maximal cliques of the graph joining near IDs. An ID near two IDs that
+/// are not near each other belongs to a cluster with each of them. IDs with
+/// no near neighbour are left out.
+pub fn clusters(ids: &[BoxId], max_distance: usize) -> Vec<Cluster<'_>> {
+    let tree = BkTree::from_ids(Distance::Hamming, ids);
+    let mut indices: HashMap<&BoxId, Vec<usize>> = HashMap::new();
+    for (index, id) in ids.iter().enumerate() {
+        indices.entry(id).or_default().push(index);
+    }
+
+    let neighbours: Vec<BTreeSet<usize>> = ids
+        .iter()
+        .enumerate()
+        .map(|(index, id)| {
+            tree.within(id, max_distance)
+                .into_iter()
+                .flat_map(|(near, _)| indices[near].iter().copied())
+                .filter(|&near| near != index)
+                .collect()
+        })
+        .collect();
+
+    let mut cliques: Vec<Vec<usize>> = Vec::new();
+    let linked: BTreeSet<usize> = (0..ids.len()).filter(|&index| !neighbours[index].is_empty()).collect();
+    extend_clique(&mut Vec::new(), linked, BTreeSet::new(), &neighbours, &mut cliques);
+    for clique in cliques.iter_mut() {
+        clique.sort_unstable();
+    }
+    cliques.sort_unstable();
+
+    return cliques
+        .into_iter()
+        .filter(|clique| clique.len() > 1)
+        .map(|clique| Cluster {
+            ids: clique.into_iter().map(|index| ids[index].as_str()).collect(),
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod test {
+    use super::clusters;
+    use super::Cluster;
+    use crate::testing::ids;
+
+    #[test]
+    fn test_clusters() {
+        let ids = ids(&["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz", "fguik"]);
+        let expected = vec![
+            Cluster {
+                ids: vec!["fghij", "fguij"],
+            },
+            Cluster {
+                ids: vec!["fguij", "fguik"],
+            },
+        ];
+
+        assert_eq!(clusters(&ids, 1), expected);
+        assert_eq!(expected[0].common(), "fgij");
+        assert_eq!(expected[1].common(), "fgui");
+    }
+
+    #[test]
+    fn test_clusters_are_mutually_near() {
+        let ids = ids(&["fghij", "fguij", "fguik", "fguik"]);
+        let found: Vec<Vec<&str>> = clusters(&ids, 2).into_iter().map(|cluster| cluster.ids).collect();
+
+        assert_eq!(found, vec![vec!["fghij", "fguij", "fguik", "fguik"]]);
+        assert_eq!(clusters(&ids, 0)[0].ids, vec!["fguik", "fguik"]);
+    }
+
+    #[test]
+    fn test_clusters_wider_distance() {
+        let ids = ids(&["abcde", "fghij", "axcye", "fguij"]);
+        let found: Vec<Vec<&str>> = clusters(&ids, 2).into_iter().map(|cluster| cluster.ids).collect();
+
+        assert_eq!(found, vec![vec!["abcde", "axcye"], vec!["fghij", "fguij"]]);
+    }
+
+    #[test]
+    fn test_common_unequal_lengths() {
+        let cluster = Cluster {
+            ids: vec!["abc", "abcd", "xbc"],
+        };
+        assert_eq!(cluster.common(), "bc");
+    }
+}
@@ -0,0 +1,41 @@
+pub mod bktree;
+pub mod box_id;
+pub mod checksum;
+pub mod cluster;
+pub mod matching;
+pub mod signature;
+#[cfg(test)]
+mod testing;
+
+use puzzle_input::Source;
+use solution::parse_lines;
+use solution::ParseError;
+use solution::Solution;
+use std::str::FromStr;
+
+pub use crate::box_id::BoxId;
+
+pub const INPUT: Source<'static> = puzzle_input::crate_input!();
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<BoxId>;
+    type PartOne = usize;
+    type PartTwo = String;
+
+    fn parse(input: &str) -> Result<Vec<BoxId>, ParseError> {
+        return parse_lines(input, BoxId::from_str);
+    }
+
+    fn part1(ids: &Vec<BoxId>) -> Result<usize, &'static str> {
+        return Ok(checksum::checksum(ids).product);
+    }
+
+    fn part2(ids: &Vec<BoxId>) -> Result<String, &'static str> {
+        return signature::near_duplicates(ids)
+            .first()
+            .map(|pair| pair.common())
+            .ok_or("No matches found");
+    }
+}
@@ -0,0 +1,61 @@
+use std::option::Option;
+
+use crate::bktree::hamming;
+use crate::box_id::BoxId;
+
+pub fn get_matches(current_id: &BoxId, all_ids: &[BoxId]) -> Option<String> {
+    let length = current_id.as_str().len();
+    for id in all_ids.iter().filter(|id| id.as_str().len() == length) {
+        let character_pairs = current_id.as_str().chars().zip(id.as_str().chars());
+        let same: String = character_pairs
+            .filter(|(x, y)| x == y)
+            .map(|(x, _)| x)
+            .collect();
+
+        if hamming(current_id, id) == 1 {
+            return Some(same);
+        }
+    }
+
+    return None;
+}
+
+pub fn find_first_match(sets: &mut Vec<BoxId>) -> Option<String> {
+    while let Some(set) = sets.pop() {
+        let found_match = get_matches(&set, sets);
+
+        if found_match.is_some() {
+            return found_match;
+        }
+    }
+
+    return None;
+}
+
+#[cfg(test)]
+mod test {
+    use super::find_first_match;
+    use super::get_matches;
+    use crate::testing::id;
+    use crate::testing::ids;
+
+    #[test]
+    fn test_get_matches() {
+        let ids = ids(&["abcde", "fguij"]);
+        assert_eq!(get_matches(&id("fghij"), &ids), Some("fgij".to_string()));
+    }
+
+    #[test]
+    fn test_get_matches_unequal_lengths() {
+        let ids = ids(&["abc", "abcd"]);
+        assert_eq!(get_matches(&id("ab"), &ids), None);
+        assert_eq!(get_matches(&id("abcde"), &ids), None);
+        assert_eq!(get_matches(&id("abx"), &ids), Some("ab".to_string()));
+    }
+
+    #[test]
+    fn test_find_first_match() {
+        let mut ids = ids(&["abcde", "fghij", "fguij"]);
+        assert_eq!(find_first_match(&mut ids), Some("fgij".to_string()));
+    }
+}
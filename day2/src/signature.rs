@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use crate::box_id::BoxId;
+
+/// Two box IDs of the same length that differ in exactly one position.
+#[derive(PartialEq, Debug)]
+pub struct NearDuplicate<'a> {
+    pub first: &'a str,
+    pub second: &'a str,
+    pub position: usize,
+}
+
+impl<'a> NearDuplicate<'a> {
+    /// The letters the two IDs have in common.
+    pub fn common(&self) -> String {
+        return self
+            .first
+            .chars()
+            .enumerate()
+            .filter(|&(position, _)| position != self.position)
+            .map(|(_, ch)| ch)
+            .collect();
+    }
+}
+
+const BASE: u64 = 0x100000001b3;
+
+/// The hash of `id` with each position in turn deleted, all computed from
+/// prefix and suffix hashes in O(len).
+fn deletion_hashes(id: &[u8]) -> Vec<u64> {
+    let mut prefixes = vec![0u64; id.len() + 1];
+    for (i, &ch) in id.iter().enumerate() {
+        prefixes[i + 1] = prefixes[i].wrapping_mul(BASE).wrapping_add(u64::from(ch));
+    }
+
+    let mut powers = vec![1u64; id.len() + 1];
+    for i in 1..powers.len() {
+        powers[i] = powers[i - 1].wrapping_mul(BASE);
+    }
+
+    let mut suffixes = vec![0u64; id.len() + 1];
+    for (i, &ch) in id.iter().enumerate().rev() {
+        suffixes[i] = u64::from(ch).wrapping_mul(powers[id.len() - i - 1]).wrapping_add(suffixes[i + 1]);
+    }
+
+    return (0..id.len())
+        .map(|position| {
+            prefixes[position]
+                .wrapping_mul(powers[id.len() - position - 1])
+                .wrapping_add(suffixes[position + 1])
+        })
+        .collect();
+}
+
+fn differs_only_at(first: &[u8], second: &[u8], position: usize) -> bool {
+    return first.len() == second.len()
+        && first[position] != second[position]
+        && first.iter().zip(second).enumerate().all(|(i, (a, b))| i == position || a == b);
+}
+
+/// Finds every pair of IDs at Hamming distance one. IDs that agree once the
+/// same position is deleted from both land in the same bucket, so only
+/// those are compared, in O(n·len) plus the cost of checking the matches.
+/// Pairs come out in the order they appear in `ids`.
+pub fn near_duplicates(ids: &[BoxId]) -> Vec<NearDuplicate<'_>> {
+    let mut buckets: HashMap<(usize, usize, u64), Vec<usize>> = HashMap::new();
+    for (index, id) in ids.iter().map(BoxId::as_bytes).enumerate() {
+        for (position, hash) in deletion_hashes(id).into_iter().enumerate() {
+            buckets.entry((id.len(), position, hash)).or_default().push(index);
+        }
+    }
+
+    let mut pairs: Vec<(usize, usize, usize)> = Vec::new();
+    for (&(_, position, _), indices) in buckets.iter().filter(|(_, indices)| indices.len() > 1) {
+        for (i, &first) in indices.iter().enumerate() {
+            for &second in &indices[i + 1..] {
+                if differs_only_at(ids[first].as_bytes(), ids[second].as_bytes(), position) {
+                    pairs.push((first, second, position));
+                }
+            }
+        }
+    }
+    pairs.sort_unstable();
+
+    return pairs
+        .into_iter()
+        .map(|(first, second, position)| NearDuplicate {
+            first: ids[first].as_str(),
+            second: ids[second].as_str(),
+            position,
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod test {
+    use super::near_duplicates;
+    use super::NearDuplicate;
+    use crate::testing::ids;
+
+    #[test]
+    fn test_single_match() {
+        let ids = ids(&["abcde", "fghij", "klmno", "pqrst", "fguij", "axcye", "wvxyz"]);
+        let matches = near_duplicates(&ids);
+
+        let expected = NearDuplicate {
+            first: "fghij",
+            second: "fguij",
+            position: 2,
+        };
+        assert_eq!(matches, vec![expected]);
+        assert_eq!(matches[0].common(), "fgij");
+    }
+
+    #[test]
+    fn test_all_matches() {
+        let ids = ids(&["abc", "abd", "xbd", "abc", "ab"]);
+        let positions: Vec<(&str, &str, usize)> = near_duplicates(&ids)
+            .iter()
+            .map(|pair| (pair.first, pair.second, pair.position))
+            .collect();
+
+        assert_eq!(
+            positions,
+            vec![("abc", "abd", 2), ("abd", "xbd", 0), ("abd", "abc", 2)]
+        );
+    }
+
+    #[test]
+    fn test_no_matches() {
+        assert!(near_duplicates(&ids(&["abc", "abc", "bca", "abcd"])).is_empty());
+    }
+}
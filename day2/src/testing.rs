@@ -0,0 +1,11 @@
+//! Helpers shared by the test modules of this crate.
+
+use crate::box_id::BoxId;
+
+pub fn id(id: &str) -> BoxId {
+    return id.parse().unwrap();
+}
+
+pub fn ids(ids: &[&str]) -> Vec<BoxId> {
+    return ids.iter().map(|text| id(text)).collect();
+}
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+const CHUNK: usize = 64;
+
+/// A grid over the whole of `usize × usize`, stored as square chunks that
+/// are only allocated once something is written to them. Memory grows with
+/// the area actually written, however far apart the written cells are.
+pub struct Grid<T> {
+    chunks: HashMap<(usize, usize), Box<[T]>>,
+}
+
+impl<T: Copy + Default> Grid<T> {
+    pub fn new() -> Grid<T> {
+        return Grid { chunks: HashMap::new() };
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> T {
+        return self
+            .chunks
+            .get(&(x / CHUNK, y / CHUNK))
+            .map_or_else(T::default, |chunk| chunk[(y % CHUNK) * CHUNK + x % CHUNK]);
+    }
+
+    /// Calls `update` with every cell from (`left`, `top`) up to, but not
+    /// including, (`right`, `bottom`), a chunk at a time.
+    pub fn update<F: FnMut(usize, usize, &mut T)>(&mut self, left: usize, top: usize, right: usize, bottom: usize, mut update: F) {
+        if left >= right || top >= bottom {
+            return;
+        }
+        for chunk_y in top / CHUNK..=(bottom - 1) / CHUNK {
+            for chunk_x in left / CHUNK..=(right - 1) / CHUNK {
+                let chunk = self
+                    .chunks
+                    .entry((chunk_x, chunk_y))
+                    .or_insert_with(|| vec![T::default(); CHUNK * CHUNK].into_boxed_slice());
+                let (origin_x, origin_y) = (chunk_x * CHUNK, chunk_y * CHUNK);
+                for y in top.max(origin_y)..bottom.min(origin_y + CHUNK) {
+                    for x in left.max(origin_x)..right.min(origin_x + CHUNK) {
+                        update(x, y, &mut chunk[(y - origin_y) * CHUNK + x - origin_x]);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every cell of every allocated chunk, in no particular order.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        return self.chunks.iter().flat_map(|(&(chunk_x, chunk_y), chunk)| {
+            chunk.iter().enumerate().map(move |(offset, &cell)| {
+                (chunk_x * CHUNK + offset % CHUNK, chunk_y * CHUNK + offset / CHUNK, cell)
+            })
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid;
+
+    #[test]
+    fn test_update_across_chunks() {
+        let mut grid: Grid<u32> = Grid::new();
+        grid.update(60, 62, 70, 66, |_, _, cell| *cell += 1);
+        grid.update(63, 63, 65, 65, |_, _, cell| *cell += 1);
+
+        assert_eq!(grid.chunks.len(), 4);
+        assert_eq!(grid.get(60, 62), 1);
+        assert_eq!(grid.get(64, 64), 2);
+        assert_eq!(grid.get(70, 66), 0);
+        assert_eq!(grid.cells().filter(|&(_, _, cell)| cell > 0).count(), 40);
+    }
+
+    #[test]
+    fn test_far_apart_cells() {
+        let mut grid: Grid<u32> = Grid::new();
+        grid.update(0, 0, 1, 1, |_, _, cell| *cell = 7);
+        grid.update(1_000_000, 1_000_000, 1_000_001, 1_000_001, |x, y, cell| *cell = (x + y) as u32);
+
+        assert_eq!(grid.chunks.len(), 2);
+        assert_eq!(grid.get(0, 0), 7);
+        assert_eq!(grid.get(1_000_000, 1_000_000), 2_000_000);
+        assert_eq!(grid.get(500_000, 500_000), 0);
+    }
+}
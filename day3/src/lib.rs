@@ -0,0 +1,651 @@
+extern crate regex;
+#[macro_use] extern crate lazy_static;
+
+mod grid;
+pub mod render;
+pub mod sweep;
+#[cfg(test)]
+mod testing;
+
+use regex::Regex;
+use crate::grid::Grid;
+use puzzle_input::Source;
+use solution::column;
+use solution::parse_lines;
+use solution::parse_number;
+use solution::ParseError;
+use solution::ParseErrorKind;
+use solution::Solution;
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::vec::Vec;
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Claim {
+    pub id: i32,
+    pub left: usize,
+    pub top: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Claim {
+    pub fn is_empty(&self) -> bool {
+        return self.width == 0 || self.height == 0;
+    }
+
+    /// Whether the two claims share at least one square inch.
+    pub fn overlaps(&self, other: &Claim) -> bool {
+        return !self.is_empty()
+            && !other.is_empty()
+            && self.left < other.left + other.width
+            && other.left < self.left + self.width
+            && self.top < other.top + other.height
+            && other.top < self.top + self.height;
+    }
+
+    /// The parts of this claim that none of `earlier` covers, as rectangles
+    /// overlapping neither each other nor `earlier`.
+    pub fn uncovered_by<'a, I: IntoIterator<Item = &'a Claim>>(&self, earlier: I) -> Vec<Claim> {
+        let mut pieces = vec![self.clone()];
+        for other in earlier {
+            pieces = pieces.into_iter().flat_map(|piece| piece.without(other)).collect();
+        }
+        return pieces;
+    }
+
+    /// This claim with `other` cut out: the bands above and below it, and
+    /// the parts to its left and right in between.
+    fn without(self, other: &Claim) -> Vec<Claim> {
+        if !self.overlaps(other) {
+            return vec![self];
+        }
+        let (right, bottom) = (self.left + self.width, self.top + self.height);
+        let (other_right, other_bottom) = (other.left + other.width, other.top + other.height);
+        let (middle_top, middle_bottom) = (self.top.max(other.top), bottom.min(other_bottom));
+        let piece = |left: usize, top: usize, right: usize, bottom: usize| Claim {
+            id: self.id,
+            left,
+            top,
+            width: right - left,
+            height: bottom - top,
+        };
+
+        let mut pieces = Vec::new();
+        if self.top < other.top {
+            pieces.push(piece(self.left, self.top, right, other.top));
+        }
+        if other_bottom < bottom {
+            pieces.push(piece(self.left, other_bottom, right, bottom));
+        }
+        if self.left < other.left {
+            pieces.push(piece(self.left, middle_top, other.left, middle_bottom));
+        }
+        if other_right < right {
+            pieces.push(piece(other_right, middle_top, right, middle_bottom));
+        }
+        return pieces;
+    }
+}
+
+/// Marks a square claimed more than once; the rest of the bits are the count.
+const SHARED: u32 = 1 << 31;
+
+/// A square inch of fabric in four bytes: zero when unclaimed, one more than
+/// the index of its claim when claimed once, and the number of claims with
+/// `SHARED` set when claimed more than once.
+#[derive(Clone, Copy, Default)]
+struct Square(u32);
+
+impl Square {
+    fn count(self) -> u32 {
+        return match self.0 {
+            0 => 0,
+            square if square & SHARED == 0 => 1,
+            square => square & !SHARED,
+        };
+    }
+
+    /// The index of the only claim covering the square, if just one does.
+    fn only_claim(self) -> Option<usize> {
+        if self.0 == 0 || self.0 & SHARED != 0 {
+            return None;
+        }
+        return Some(self.0 as usize - 1);
+    }
+
+    fn add(&mut self, index: u32) {
+        self.0 = match self.count() {
+            0 => index + 1,
+            count => SHARED | (count + 1),
+        };
+    }
+}
+
+/// The claims laid out on the fabric, with the number of claims covering
+/// each square inch. Only the parts of the fabric claims reach are stored,
+/// so the fabric can be as large as the claims need. Claims sharing an id
+/// are parts of one claim, which covers every square inch any of them do.
+pub struct FabricSheet {
+    sheet: Grid<Square>,
+    /// The left, top, right and bottom edges of the claimed area.
+    bounds: Option<(usize, usize, usize, usize)>,
+    /// Every claim laid out, split where a claim with the same id already
+    /// covered the fabric, so no two of them with an id overlap.
+    claims: Vec<Claim>,
+    /// The indices into `claims` of the parts of each claim id.
+    indices: HashMap<i32, Vec<usize>>,
+    invalid_claims: HashSet<i32>,
+}
+
+impl FabricSheet {
+    pub fn new() -> FabricSheet {
+        return FabricSheet { 
+            sheet: Grid::new(),
+            bounds: None,
+            claims: Vec::new(),
+            indices: HashMap::new(),
+            invalid_claims: HashSet::new(),
+        }
+    }
+
+    /// The smallest rectangle holding every claim, as its left, top, right
+    /// and bottom edges, or `None` before any claim has been added.
+    pub fn bounds(&self) -> Option<(usize, usize, usize, usize)> {
+        return self.bounds;
+    }
+
+    /// Lays `claim` out on the sheet. Claims covering no fabric are left
+    /// out, and fabric already claimed under the same id is not claimed
+    /// again.
+    pub fn add_claim(&mut self, claim: &Claim) {
+        if claim.is_empty() {
+            return;
+        }
+        let pieces = match self.indices.get(&claim.id) {
+            Some(indices) => claim.uncovered_by(indices.iter().map(|&index| &self.claims[index])),
+            None => vec![claim.clone()],
+        };
+        for piece in pieces {
+            self.add_piece(piece);
+        }
+    }
+
+    /// Lays out a claim that overlaps no other claim with its id.
+    fn add_piece(&mut self, claim: Claim) {
+        // Whoever claimed a square first is only contested once a second
+        // claim arrives; later arrivals find them marked already.
+        let index = u32::try_from(self.claims.len())
+            .ok()
+            .filter(|&index| index + 1 < SHARED)
+            .expect("a sheet holds fewer than 2^31 - 1 claims");
+        let mut contested = false;
+        let mut first_claimants: Vec<usize> = Vec::new();
+        let (right, bottom) = (claim.left + claim.width, claim.top + claim.height);
+        self.sheet.update(claim.left, claim.top, right, bottom, |_, _, square| {
+            if square.count() > 0 {
+                contested = true;
+            }
+            first_claimants.extend(square.only_claim());
+            square.add(index);
+        });
+
+        if contested {
+            self.invalid_claims.insert(claim.id);
+        }
+        for first in first_claimants {
+            self.invalid_claims.insert(self.claims[first].id);
+        }
+        self.bounds = Some(match self.bounds {
+            Some((left, top, old_right, old_bottom)) => (
+                left.min(claim.left),
+                top.min(claim.top),
+                old_right.max(right),
+                old_bottom.max(bottom),
+            ),
+            None => (claim.left, claim.top, right, bottom),
+        });
+        self.indices.entry(claim.id).or_default().push(index as usize);
+        self.claims.push(claim);
+    }
+
+    /// How many claims cover the square inch at (`x`, `y`).
+    pub fn claim_count(&self, x: usize, y: usize) -> u32 {
+        return self.sheet.get(x, y).count();
+    }
+
+    /// The square inches covered by at least `count` claims, row by row.
+    /// Unclaimed fabric is never listed, even for a `count` of zero.
+    pub fn claimed_at_least(&self, count: u32) -> Vec<(usize, usize)> {
+        let mut cells: Vec<(usize, usize)> = self
+            .sheet
+            .cells()
+            .filter(|&(_, _, square)| square.count() >= count.max(1))
+            .map(|(x, y, _)| (y, x))
+            .collect();
+        cells.sort_unstable();
+        return cells.into_iter().map(|(y, x)| (x, y)).collect();
+    }
+
+    /// The ids of the claims sharing fabric with claim `id`, in ascending
+    /// order, or `None` if no claim on the sheet has that id.
+    pub fn overlapping(&self, id: i32) -> Option<Vec<i32>> {
+        let indices = self.indices.get(&id)?;
+        if !self.invalid_claims.contains(&id) {
+            return Some(Vec::new());
+        }
+        let mut overlapping: Vec<i32> = self
+            .contested()
+            .filter(|other| other.id != id && indices.iter().any(|&index| other.overlaps(&self.claims[index])))
+            .map(|other| other.id)
+            .collect();
+        overlapping.sort_unstable();
+        overlapping.dedup();
+        return Some(overlapping);
+    }
+
+    /// The claims that overlap at least one other.
+    fn contested(&self) -> impl Iterator<Item = &Claim> {
+        return self.claims.iter().filter(move |claim| self.invalid_claims.contains(&claim.id));
+    }
+
+    /// Every claim on the sheet, with the ids of the claims it overlaps.
+    /// Only contested claims are compared with each other.
+    pub fn overlap_graph(&self) -> BTreeMap<i32, BTreeSet<i32>> {
+        let mut graph: BTreeMap<i32, BTreeSet<i32>> =
+            self.claims.iter().map(|claim| (claim.id, BTreeSet::new())).collect();
+        let contested: Vec<&Claim> = self.contested().collect();
+        for (i, claim) in contested.iter().enumerate() {
+            for other in contested[i + 1..].iter().filter(|other| other.overlaps(claim)) {
+                graph.entry(claim.id).or_default().insert(other.id);
+                graph.entry(other.id).or_default().insert(claim.id);
+            }
+        }
+        return graph;
+    }
+
+    /// The ids of every claim that overlaps no other, in ascending order.
+    pub fn get_valid_claims(&self) -> Vec<i32> {
+        let mut valid: Vec<i32> = self
+            .indices
+            .keys()
+            .copied()
+            .filter(|id| !self.invalid_claims.contains(id))
+            .collect();
+        valid.sort_unstable();
+        return valid;
+    }
+
+    pub fn nsquares_claimed_twice(&self) -> usize {
+        return self.sheet.cells().filter(|&(_, _, square)| square.count() >= 2).count();
+    }
+}
+
+impl Default for FabricSheet {
+    fn default() -> FabricSheet {
+        return FabricSheet::new();
+    }
+}
+
+const CLAIM_HINT: &str = "expected `#<id> @ <x>,<y>: <w>x<h>`";
+
+fn parse_claim(input: &str) -> Result<Claim, ParseError> {
+    lazy_static! {
+        static ref pattern: Regex = Regex::new(r"^#(\S*)\s+@\s+(\S*?),(\S*?):\s+(\S*?)x(\S*)$").unwrap();
+    }
+    let matches = pattern
+        .captures(input)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidFormat, 1, input))?;
+
+    let field = |index: usize| {
+        let field = matches.get(index).unwrap();
+        (field.as_str(), column(input, field.start()))
+    };
+
+    let (id, id_column) = field(1);
+    let (left, left_column) = field(2);
+    let (top, top_column) = field(3);
+    let (width, width_column) = field(4);
+    let (height, height_column) = field(5);
+
+    let claim = Claim {
+        id: parse_number(id, id_column)?,
+        left: parse_number(left, left_column)?,
+        top: parse_number(top, top_column)?,
+        width: parse_number(width, width_column)?,
+        height: parse_number(height, height_column)?,
+    };
+    // Every right and bottom edge is then a valid coordinate, so neither
+    // the sheet nor the sweep has to check their sums.
+    if claim.left.checked_add(claim.width).is_none() {
+        return Err(ParseError::new(ParseErrorKind::Expected("a width that ends on the fabric"), width_column, width));
+    }
+    if claim.top.checked_add(claim.height).is_none() {
+        return Err(ParseError::new(ParseErrorKind::Expected("a height that ends on the fabric"), height_column, height));
+    }
+    return Ok(claim);
+}
+
+impl FromStr for Claim {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Claim, ParseError> {
+        return parse_claim(input).map_err(|error| error.with_hint(CLAIM_HINT));
+    }
+}
+
+/// Lays every claim out on a new sheet.
+pub fn build_fabric_sheet(claims: &[Claim]) -> FabricSheet {
+    let mut fabric_sheet = FabricSheet::new();
+
+    for claim in claims {
+        fabric_sheet.add_claim(claim);
+    }
+
+    return fabric_sheet;
+}
+
+/// The answer to part 2: the claims left intact, overlapping no other
+/// claim, in ascending order. A single claim is shown as just its id.
+#[derive(PartialEq, Debug)]
+pub struct IntactClaims(pub Vec<i32>);
+
+impl fmt::Display for IntactClaims {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self.0.as_slice() {
+            [] => write!(f, "no intact claims"),
+            [id] => write!(f, "{}", id),
+            ids => {
+                let ids: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+                write!(f, "{} intact claims: {}", ids.len(), ids.join(", "))
+            }
+        };
+    }
+}
+
+pub const INPUT: Source<'static> = puzzle_input::crate_input!();
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Claim>;
+    type PartOne = usize;
+    type PartTwo = IntactClaims;
+
+    fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+        return parse_lines(input, Claim::from_str);
+    }
+
+    fn part1(claims: &Vec<Claim>) -> Result<usize, &'static str> {
+        let fabric_sheet = build_fabric_sheet(claims);
+        return Ok(fabric_sheet.nsquares_claimed_twice());
+    }
+
+    fn part2(claims: &Vec<Claim>) -> Result<IntactClaims, &'static str> {
+        let fabric_sheet = build_fabric_sheet(claims);
+        return Ok(IntactClaims(fabric_sheet.get_valid_claims()));
+    }
+}
+
+/// Day 3 solved with a sweep line over the claims' edges instead of laying
+/// every claim out on the fabric.
+pub struct Day3Sweep;
+
+impl Solution for Day3Sweep {
+    type Input = Vec<Claim>;
+    type PartOne = usize;
+    type PartTwo = IntactClaims;
+
+    fn parse(input: &str) -> Result<Vec<Claim>, ParseError> {
+        return Day3::parse(input);
+    }
+
+    fn part1(claims: &Vec<Claim>) -> Result<usize, &'static str> {
+        return Ok(sweep::sweep(claims).area);
+    }
+
+    fn part2(claims: &Vec<Claim>) -> Result<IntactClaims, &'static str> {
+        return Ok(IntactClaims(sweep::sweep(claims).intact));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Claim;
+    use super::Day3;
+    use super::Day3Sweep;
+    use super::FabricSheet;
+    use super::IntactClaims;
+    use super::Square;
+    use super::CLAIM_HINT;
+    use crate::testing::example_sheet;
+    use solution::ParseError;
+    use solution::ParseErrorKind;
+    use solution::Solution;
+
+    #[test]
+    fn test_claim_from_string() {
+        let parsed = "#123 @ 3,2: 5x4".parse::<Claim>().unwrap();
+        let expected = Claim {
+            id: 123,
+            left: 3,
+            top: 2,
+            width: 5,
+            height: 4,
+        };
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn test_claim_invalid_number() {
+        let result = "#123 @ 3,2: 5x4a".parse::<Claim>();
+        let expected = ParseError::new(ParseErrorKind::InvalidNumber, 15, "4a").with_hint(CLAIM_HINT);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_claim_invalid_format() {
+        let result = "#123 @ 3,2 5x4".parse::<Claim>();
+        let expected = ParseError::new(ParseErrorKind::InvalidFormat, 1, "#123 @ 3,2 5x4").with_hint(CLAIM_HINT);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_claim_past_the_fabric_edge() {
+        let wide = "#1 @ 18446744073709551615,0: 2x2".parse::<Claim>();
+        let tall = "#1 @ 0,18446744073709551614: 2x2".parse::<Claim>();
+        let edge = "#1 @ 18446744073709551614,0: 1x1".parse::<Claim>();
+
+        assert_eq!(
+            wide,
+            Err(ParseError::new(ParseErrorKind::Expected("a width that ends on the fabric"), 30, "2").with_hint(CLAIM_HINT))
+        );
+        assert_eq!(
+            tall,
+            Err(ParseError::new(ParseErrorKind::Expected("a height that ends on the fabric"), 32, "2").with_hint(CLAIM_HINT))
+        );
+        assert!(edge.is_ok());
+    }
+
+    #[test]
+    fn test_add_claim() {
+        let mut sheet = FabricSheet::new();
+        let claim = Claim { id: 1, left: 0, top: 0, width: 1, height: 1 };
+
+        sheet.add_claim(&claim);
+
+        assert_eq!(sheet.claim_count(0, 0), 1);
+    }
+
+    #[test]
+    fn test_add_claim_past_1000() {
+        let mut sheet = FabricSheet::new();
+        sheet.add_claim(&Claim { id: 1, left: 1500, top: 2000, width: 3, height: 2 });
+        sheet.add_claim(&Claim { id: 2, left: 1502, top: 1999, width: 2, height: 2 });
+        sheet.add_claim(&Claim { id: 3, left: 1400, top: 2001, width: 1, height: 1 });
+
+        assert_eq!(sheet.bounds(), Some((1400, 1999, 1504, 2002)));
+        assert_eq!(sheet.claim_count(1500, 2000), 1);
+        assert_eq!(sheet.claim_count(1400, 2001), 1);
+        assert_eq!(sheet.claimed_at_least(2), vec![(1502, 2000)]);
+        assert_eq!(sheet.get_valid_claims(), vec![3]);
+    }
+
+    #[test]
+    fn test_grows_one_claim_at_a_time() {
+        let mut sheet = FabricSheet::new();
+        for i in 0..500 {
+            sheet.add_claim(&Claim { id: i as i32, left: 3 * i, top: 2 * i, width: 4, height: 3 });
+        }
+
+        assert_eq!(sheet.bounds(), Some((0, 0, 1501, 1001)));
+        assert_eq!(sheet.claimed_at_least(2).len(), 499);
+        assert_eq!(sheet.claim_count(3, 2), 2);
+        assert!(sheet.get_valid_claims().is_empty());
+    }
+
+    #[test]
+    fn test_square_fits_in_four_bytes() {
+        assert_eq!(std::mem::size_of::<Square>(), 4);
+    }
+
+    #[test]
+    fn test_far_apart_claims() {
+        let mut sheet = FabricSheet::new();
+        sheet.add_claim(&Claim { id: 1, left: 0, top: 0, width: 2, height: 2 });
+        sheet.add_claim(&Claim { id: 2, left: 1_000_000, top: 1_000_000, width: 2, height: 2 });
+
+        assert_eq!(sheet.bounds(), Some((0, 0, 1_000_002, 1_000_002)));
+        assert_eq!(sheet.claim_count(1_000_001, 1_000_001), 1);
+        assert_eq!(sheet.get_valid_claims(), vec![1, 2]);
+        assert_eq!(FabricSheet::new().bounds(), None);
+    }
+
+    #[test]
+    fn test_add_twice() {
+        let mut sheet = FabricSheet::new();
+        let first_claim = Claim { id: 1, left: 0, top: 0, width: 1, height: 1 };
+        let second_claim = Claim { id: 2, left: 0, top: 0, width: 1, height: 1 };
+
+        sheet.add_claim(&first_claim);
+        sheet.add_claim(&second_claim);
+
+        assert_eq!(sheet.claimed_at_least(2), vec![(0, 0)]);
+        assert_eq!(sheet.get_valid_claims().len(), 0)
+    }
+
+    #[test]
+    fn add_same_twice() {
+        let mut sheet = FabricSheet::new();
+        let claim = Claim { id: 1, left: 0, top: 0, width: 1, height: 1 };
+
+        sheet.add_claim(&claim);
+        sheet.add_claim(&claim);
+
+        assert_eq!(sheet.nsquares_claimed_twice(), 0);
+        assert_eq!(sheet.get_valid_claims().len(), 1)
+    }
+
+    #[test]
+    fn test_parts_of_one_claim() {
+        let mut sheet = FabricSheet::new();
+        sheet.add_claim(&Claim { id: 1, left: 0, top: 0, width: 3, height: 3 });
+        sheet.add_claim(&Claim { id: 1, left: 2, top: 2, width: 3, height: 3 });
+        sheet.add_claim(&Claim { id: 2, left: 4, top: 4, width: 2, height: 2 });
+        sheet.add_claim(&Claim { id: 3, left: 9, top: 0, width: 1, height: 1 });
+
+        assert_eq!(sheet.claim_count(2, 2), 1);
+        assert_eq!(sheet.claimed_at_least(2), vec![(4, 4)]);
+        assert_eq!(sheet.overlapping(1), Some(vec![2]));
+        assert_eq!(sheet.overlapping(2), Some(vec![1]));
+        assert_eq!(sheet.get_valid_claims(), vec![3]);
+    }
+
+    #[test]
+    fn test_uncovered_by() {
+        let claim = Claim { id: 1, left: 0, top: 0, width: 4, height: 4 };
+        let hole = Claim { id: 1, left: 1, top: 1, width: 2, height: 2 };
+        let pieces = claim.uncovered_by(vec![&hole]);
+
+        assert_eq!(pieces.iter().map(|piece| piece.width * piece.height).sum::<usize>(), 12);
+        assert!(pieces.iter().all(|piece| !piece.overlaps(&hole)));
+        assert_eq!(claim.uncovered_by(vec![&claim]), vec![]);
+        assert_eq!(hole.uncovered_by(&[]), vec![hole.clone()]);
+    }
+
+    #[test]
+    fn test_claim_counts() {
+        let sheet = example_sheet();
+
+        assert_eq!(sheet.claim_count(3, 3), 3);
+        assert_eq!(sheet.claim_count(4, 4), 2);
+        assert_eq!(sheet.claim_count(5, 5), 1);
+        assert_eq!(sheet.claim_count(0, 0), 0);
+        assert_eq!(sheet.claim_count(100, 3), 0);
+        assert_eq!(sheet.claimed_at_least(3), vec![(3, 3)]);
+        assert_eq!(sheet.claimed_at_least(2).len(), 4);
+        assert_eq!(sheet.nsquares_claimed_twice(), 4);
+    }
+
+    #[test]
+    fn test_overlapping() {
+        let sheet = example_sheet();
+
+        assert_eq!(sheet.overlapping(1), Some(vec![2, 4]));
+        assert_eq!(sheet.overlapping(3), Some(vec![]));
+        assert_eq!(sheet.overlapping(5), None);
+    }
+
+    #[test]
+    fn test_overlap_graph() {
+        let graph = example_sheet().overlap_graph();
+        let edges: Vec<(i32, Vec<i32>)> = graph
+            .into_iter()
+            .map(|(id, neighbours)| (id, neighbours.into_iter().collect()))
+            .collect();
+
+        assert_eq!(edges, vec![(1, vec![2, 4]), (2, vec![1, 4]), (3, vec![]), (4, vec![1, 2])]);
+    }
+
+    #[test]
+    fn test_valid_claims_sorted() {
+        let mut sheet = FabricSheet::new();
+        for id in [9, 4, 7, 1].iter() {
+            sheet.add_claim(&Claim { id: *id, left: 2 * *id as usize, top: 0, width: 1, height: 1 });
+        }
+        sheet.add_claim(&Claim { id: 5, left: 14, top: 0, width: 1, height: 1 });
+
+        assert_eq!(sheet.get_valid_claims(), vec![1, 4, 9]);
+    }
+
+    #[test]
+    fn test_intact_claims_answer() {
+        let separate = vec![
+            Claim { id: 2, left: 0, top: 0, width: 1, height: 1 },
+            Claim { id: 1, left: 5, top: 5, width: 1, height: 1 },
+        ];
+        let overlapping = vec![
+            Claim { id: 1, left: 0, top: 0, width: 2, height: 2 },
+            Claim { id: 2, left: 1, top: 1, width: 2, height: 2 },
+        ];
+
+        assert_eq!(Day3::part2(&separate), Ok(IntactClaims(vec![1, 2])));
+        assert_eq!(Day3Sweep::part2(&separate), Ok(IntactClaims(vec![1, 2])));
+        assert_eq!(Day3::part2(&overlapping), Ok(IntactClaims(vec![])));
+        assert_eq!(Day3Sweep::part2(&overlapping), Ok(IntactClaims(vec![])));
+    }
+
+    #[test]
+    fn test_intact_claims_display() {
+        assert_eq!(IntactClaims(vec![560]).to_string(), "560");
+        assert_eq!(IntactClaims(vec![3, 12, 40]).to_string(), "3 intact claims: 3, 12, 40");
+        assert_eq!(IntactClaims(vec![]).to_string(), "no intact claims");
+    }
+}
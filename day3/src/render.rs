@@ -0,0 +1,285 @@
+use std::convert::TryFrom;
+use std::io;
+use std::io::Write;
+
+use crate::FabricSheet;
+
+pub type Rgb = [u8; 3];
+
+const BACKGROUND: Rgb = [24, 24, 24];
+const OUTLINE: Rgb = [255, 255, 255];
+/// The most pixels a heat map may have, and the longest side: enough for
+/// any real input, while claims far apart cannot exhaust memory.
+const MAX_PIXELS: usize = 1 << 26;
+const MAX_SIDE: usize = 1 << 16;
+
+/// Overlaps shade from the first colour, claimed twice, to the second at the
+/// highest claim count on the sheet.
+const OVERLAP: (Rgb, Rgb) = ([255, 214, 0], [200, 0, 0]);
+
+/// An RGB image, one pixel per square inch of fabric.
+#[derive(PartialEq, Debug)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        return Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        };
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Rgb {
+        return self.pixels[y * self.width + x];
+    }
+
+    fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Writes the image as a binary PPM (P6).
+    pub fn write_ppm<W: Write>(&self, mut output: W) -> io::Result<()> {
+        write!(output, "P6\n{} {}\n255\n", self.width, self.height)?;
+        for pixel in &self.pixels {
+            output.write_all(pixel)?;
+        }
+        return output.flush();
+    }
+
+    /// Writes the image as an 8-bit RGB PNG. The pixel data is stored
+    /// without compression, which keeps the encoder free of dependencies.
+    pub fn write_png<W: Write>(&self, mut output: W) -> io::Result<()> {
+        if self.width == 0 || self.height == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "A PNG needs at least one pixel"));
+        }
+
+        let side = |side: usize| {
+            u32::try_from(side).map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "A PNG side must fit in 32 bits"))
+        };
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&side(self.width)?.to_be_bytes());
+        header.extend_from_slice(&side(self.height)?.to_be_bytes());
+        // Bit depth 8, truecolour, default compression, filtering and no interlacing.
+        header.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+        let mut scanlines = Vec::with_capacity(self.height * (1 + 3 * self.width));
+        for row in self.pixels.chunks(self.width) {
+            scanlines.push(0);
+            for pixel in row {
+                scanlines.extend_from_slice(pixel);
+            }
+        }
+
+        output.write_all(b"\x89PNG\r\n\x1a\n")?;
+        write_chunk(&mut output, b"IHDR", &header)?;
+        write_chunk(&mut output, b"IDAT", &zlib_stored(&scanlines))?;
+        write_chunk(&mut output, b"IEND", &[])?;
+        return output.flush();
+    }
+}
+
+fn write_chunk<W: Write>(output: &mut W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    output.write_all(&(data.len() as u32).to_be_bytes())?;
+    output.write_all(kind)?;
+    output.write_all(data)?;
+    let checksum = crc32(kind.iter().chain(data));
+    return output.write_all(&checksum.to_be_bytes());
+}
+
+fn crc32<'a, I: IntoIterator<Item = &'a u8>>(bytes: I) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        }
+    }
+    return !crc;
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    return (b << 16) | a;
+}
+
+/// Wraps `data` in a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        stream.push(u8::from(last));
+        stream.extend_from_slice(&(block.len() as u16).to_le_bytes());
+        stream.extend_from_slice(&(!(block.len() as u16)).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    return stream;
+}
+
+/// A colour of its own for every claim id, spread around the colour wheel.
+fn claim_color(id: i32, bright: bool) -> Rgb {
+    let hue = (id as u32).wrapping_mul(2_654_435_761) % 360;
+    let (saturation, value) = if bright { (0.9, 1.0) } else { (0.5, 0.55) };
+
+    let chroma = value * saturation;
+    let sector = f64::from(hue) / 60.0;
+    let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (red, green, blue) = match hue / 60 {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+    let channel = |component: f64| ((component + value - chroma) * 255.0).round() as u8;
+    return [channel(red), channel(green), channel(blue)];
+}
+
+fn overlap_color(count: u32, max_count: u32) -> Rgb {
+    let (from, to) = OVERLAP;
+    let t = f64::from(count - 2) / f64::from((max_count - 2).max(1));
+    let mut color = [0; 3];
+    for (channel, (from, to)) in color.iter_mut().zip(from.iter().zip(to.iter())) {
+        *channel = (f64::from(*from) + (f64::from(*to) - f64::from(*from)) * t).round() as u8;
+    }
+    return color;
+}
+
+/// A heat map of the sheet: fabric claimed once takes the colour of its
+/// claim, overlaps are shaded by how many claims cover them, and claims
+/// that overlap nothing are drawn bright and outlined. The image spans the
+/// claims' bounding box, and an empty sheet renders as a single background
+/// pixel. A bounding box past `MAX_SIDE` or `MAX_PIXELS` is an error.
+pub fn heat_map(sheet: &FabricSheet) -> Result<Image, String> {
+    let (origin_x, origin_y, right, bottom) = sheet.bounds().unwrap_or((0, 0, 1, 1));
+    let (width, height) = (right - origin_x, bottom - origin_y);
+    if width > MAX_SIDE || height > MAX_SIDE || width * height > MAX_PIXELS {
+        return Err(format!(
+            "image would be {}x{} pixels, but a heat map is at most {} pixels a side and {} in total",
+            width, height, MAX_SIDE, MAX_PIXELS
+        ));
+    }
+    let mut image = Image::new(width, height, BACKGROUND);
+    let max_count = sheet.sheet.cells().map(|(_, _, square)| square.count()).max().unwrap_or(0);
+    let valid = sheet.get_valid_claims();
+
+    for claim in &sheet.claims {
+        let intact = valid.binary_search(&claim.id).is_ok();
+        let (left, top) = (claim.left - origin_x, claim.top - origin_y);
+        let (right, bottom) = (left + claim.width, top + claim.height);
+        for y in top..bottom {
+            for x in left..right {
+                let on_edge = x == left || y == top || x + 1 == right || y + 1 == bottom;
+                let color = match sheet.claim_count(x + origin_x, y + origin_y) {
+                    1 if intact && on_edge => OUTLINE,
+                    1 => claim_color(claim.id, intact),
+                    count => overlap_color(count, max_count),
+                };
+                image.set(x, y, color);
+            }
+        }
+    }
+
+    return Ok(image);
+}
+
+#[cfg(test)]
+mod test {
+    use super::adler32;
+    use super::claim_color;
+    use super::crc32;
+    use super::heat_map;
+    use super::zlib_stored;
+    use super::Image;
+    use super::BACKGROUND;
+    use super::OUTLINE;
+    use super::OVERLAP;
+    use crate::Claim;
+    use crate::testing::example_sheet;
+    use crate::FabricSheet;
+
+    #[test]
+    fn test_heat_map() {
+        let image = heat_map(&example_sheet()).unwrap();
+
+        assert_eq!((image.width, image.height), (7, 7));
+        // Pixels are relative to the sheet's top left corner at (1, 1).
+        assert_eq!(image.pixel(0, 0), BACKGROUND);
+        assert_eq!(image.pixel(0, 2), claim_color(1, false));
+        assert_eq!(image.pixel(5, 0), claim_color(2, false));
+        assert_eq!(image.pixel(2, 2), OVERLAP.1);
+        assert_eq!(image.pixel(3, 3), OVERLAP.0);
+        assert_eq!(image.pixel(4, 4), OUTLINE);
+        assert_eq!(image.pixel(5, 5), claim_color(3, true));
+    }
+
+    #[test]
+    fn test_heat_map_of_empty_sheet() {
+        assert_eq!(heat_map(&FabricSheet::new()), Ok(Image::new(1, 1, BACKGROUND)));
+    }
+
+    #[test]
+    fn test_heat_map_too_large() {
+        let mut sheet = FabricSheet::new();
+        sheet.add_claim(&Claim { id: 1, left: 0, top: 0, width: 1, height: 1 });
+        sheet.add_claim(&Claim { id: 2, left: 4_000_000, top: 4_000_000, width: 1, height: 1 });
+
+        assert_eq!(
+            heat_map(&sheet),
+            Err("image would be 4000001x4000001 pixels, but a heat map is at most 65536 pixels a side and 67108864 in total".to_string())
+        );
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let mut image = Image::new(2, 1, [0, 0, 0]);
+        image.set(1, 0, [1, 2, 3]);
+        let mut output = Vec::new();
+        image.write_ppm(&mut output).unwrap();
+
+        assert_eq!(output, b"P6\n2 1\n255\n\x00\x00\x00\x01\x02\x03".to_vec());
+    }
+
+    #[test]
+    fn test_write_png() {
+        let image = Image::new(2, 2, [10, 20, 30]);
+        let mut output = Vec::new();
+        image.write_png(&mut output).unwrap();
+
+        assert_eq!(&output[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&output[8..33], b"\x00\x00\x00\x0dIHDR\x00\x00\x00\x02\x00\x00\x00\x02\x08\x02\x00\x00\x00\xfd\xd4\x9a\x73");
+        assert_eq!(&output[output.len() - 12..], b"\x00\x00\x00\x00IEND\xae\x42\x60\x82");
+        assert!(Image::new(0, 3, BACKGROUND).write_png(&mut Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_zlib_stored_blocks() {
+        let data = vec![7; 0x1_0001];
+        let stream = zlib_stored(&data);
+
+        assert_eq!(stream.len(), 2 + 2 * 5 + data.len() + 4);
+        assert_eq!(&stream[2..7], &[0, 0xff, 0xff, 0, 0]);
+        assert_eq!(&stream[0x1_0006..0x1_000b], &[1, 2, 0, 0xfd, 0xff]);
+        assert_eq!(zlib_stored(&[]), vec![0x78, 0x01, 1, 0, 0, 0xff, 0xff, 0, 0, 0, 1]);
+    }
+}
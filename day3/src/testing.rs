@@ -0,0 +1,20 @@
+//! Helpers shared by the test modules of this crate.
+
+use crate::Claim;
+use crate::FabricSheet;
+
+/// Four claims on a sheet: 1, 2 and 4 overlap each other, with all three
+/// on (3, 3), while 3 overlaps nothing.
+pub fn example_sheet() -> FabricSheet {
+    let claims = vec![
+        Claim { id: 1, left: 1, top: 3, width: 4, height: 4 },
+        Claim { id: 2, left: 3, top: 1, width: 4, height: 4 },
+        Claim { id: 3, left: 5, top: 5, width: 3, height: 3 },
+        Claim { id: 4, left: 3, top: 3, width: 1, height: 1 },
+    ];
+    let mut sheet = FabricSheet::new();
+    for claim in &claims {
+        sheet.add_claim(claim);
+    }
+    return sheet;
+}
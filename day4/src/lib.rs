@@ -0,0 +1,342 @@
+extern crate chrono;
+extern crate regex;
+#[macro_use] extern crate lazy_static;
+
+use regex::Regex;
+use puzzle_input::Source;
+use solution::column;
+use solution::parse_lines;
+use solution::parse_number;
+use solution::ParseError;
+use solution::ParseErrorKind;
+use solution::Solution;
+use chrono::prelude::*;
+use std::cmp::PartialOrd;
+use std::cmp::Ord;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum GuardAction {
+    WakeUp,
+    FallAsleep,
+    StartShift(u16),
+}
+
+impl FromStr for GuardAction {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<GuardAction, ParseError> {
+        lazy_static! {
+            static ref matcher: Regex = Regex::new(r"^(?:Guard #(\S*)\s+)?(.*)$").unwrap();
+        }
+        let matched = matcher.captures(input).unwrap();
+        let action = matched.get(2).unwrap();
+
+        let guard = match matched.get(1) {
+            Some(guard) => Some(parse_number::<u16>(guard.as_str(), column(input, guard.start()))?),
+            None => None,
+        };
+
+        return match (action.as_str(), guard) {
+            ("begins shift", Some(guard)) => Ok(GuardAction::StartShift(guard)),
+            ("begins shift", None) => Err(ParseError::new(ParseErrorKind::Expected("`Guard #<id>`"), 1, input)),
+            ("falls asleep", None) => Ok(GuardAction::FallAsleep),
+            ("wakes up", None) => Ok(GuardAction::WakeUp),
+            (text, _) => Err(ParseError::new(
+                ParseErrorKind::Expected("`begins shift`, `falls asleep` or `wakes up`"),
+                column(input, action.start()),
+                text,
+            )),
+        };
+    }
+}
+
+#[derive(Debug, Eq)]
+pub struct Record {
+    pub date: DateTime<Utc>,
+    pub action: GuardAction,
+}
+
+const RECORD_HINT: &str = "expected `[<yyyy-mm-dd> <hh:mm>] <action>`";
+
+fn parse_record(input: &str) -> Result<Record, ParseError> {
+    lazy_static! {
+        static ref matcher: Regex = Regex::new(r"^\[(.*?)\]\s*(.*)$").unwrap();
+    }
+    let matched = matcher
+        .captures(input)
+        .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidFormat, 1, input))?;
+    let date = matched.get(1).unwrap();
+    let action = matched.get(2).unwrap();
+
+    let date = NaiveDateTime::parse_from_str(date.as_str(), "%Y-%m-%d %H:%M")
+        .map(|date| Utc.from_utc_datetime(&date))
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidDate, column(input, date.start()), date.as_str()))?;
+
+    let action = action
+        .as_str()
+        .parse::<GuardAction>()
+        .map_err(|error| error.shift(column(input, action.start()) - 1))?;
+
+    return Ok(Record {
+        date,
+        action,
+    });
+}
+
+impl FromStr for Record {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Record, ParseError> {
+        return parse_record(input).map_err(|error| error.with_hint(RECORD_HINT));
+    }
+}
+
+impl Ord for Record {
+    fn cmp(&self, other: &Record) -> Ordering {
+        return self.date.cmp(&other.date);
+    }
+}
+
+impl PartialOrd for Record {
+    fn partial_cmp(&self, other: &Record) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl PartialEq for Record {
+    fn eq(&self, other: &Record) -> bool {
+        return self.date == other.date;
+    }
+}
+
+pub struct Schedule {
+    minutes_asleep_by_guard: HashMap<u16, [i32 ; 60]>
+}
+
+impl Schedule {
+    pub fn new(records: &Vec<Record>) -> Schedule {
+        let mut minutes_asleep_by_guard: HashMap<u16, [i32; 60]> = HashMap::new();
+        let mut current_guard: Option<u16> = None;
+        let mut began_sleeping_at: Option<DateTime<Utc>> = None;
+
+        for record in records {
+            match record.action {
+                GuardAction::StartShift(guard_id) => {
+                    minutes_asleep_by_guard.entry(guard_id).or_insert([0 ; 60]);
+
+                    current_guard = Some(guard_id);
+                    began_sleeping_at = None;
+                }
+                GuardAction::FallAsleep => {
+                    began_sleeping_at = Some(record.date);
+                }
+                GuardAction::WakeUp => {
+                    if let (Some(current_guard), Some(began_sleeping_at)) = (current_guard, began_sleeping_at) {
+                        let minutes_slept = record.date.signed_duration_since(began_sleeping_at).num_minutes();
+                        let mut minutes_for_guard: [i32; 60] = *minutes_asleep_by_guard.get(&current_guard).unwrap();
+
+                        for minute in 0..minutes_slept {
+                            let minute_to_set: usize = ((began_sleeping_at.minute() + (minute as u32)) % 60) as usize;
+                            minutes_for_guard[minute_to_set] += 1;
+                        }
+                        minutes_asleep_by_guard.insert(current_guard, minutes_for_guard);
+                    }
+                }
+            }
+        }
+        return Schedule { minutes_asleep_by_guard }
+    }
+
+    /// The guard who slept the most minutes, and how many, or `None` if
+    /// nobody slept.
+    pub fn find_sleepiest_guard(&self) -> Option<(u16, i32)> {
+        let mut max = 0;
+        let mut max_guard = None;
+        for (guard, values) in &self.minutes_asleep_by_guard {
+            let sleep_minutes: i32 = values.iter().sum();
+            if sleep_minutes > max {
+                max_guard = Some(*guard);
+                max = sleep_minutes;
+            }
+        }
+
+        return max_guard.map(|guard| (guard, max));
+    }
+
+    /// The guard most often asleep on the same minute, and that minute, or
+    /// `None` if nobody slept.
+    pub fn find_most_consistent_sleep_guard(&self) -> Option<(u16, i32)> {
+        let mut max = 0;
+        let mut max_guard = None;
+        let mut max_freq_index = 0;
+
+        for (&guard, values) in &self.minutes_asleep_by_guard {
+            for (index, &freq) in values.iter().enumerate() {
+                if freq > max {
+                    max = freq;
+                    max_guard = Some(guard);
+                    max_freq_index = index;
+                }
+            }
+        }
+
+        return max_guard.map(|guard| (guard, max_freq_index as i32));
+    }
+
+    /// The minute `guard` was most often asleep, or `None` if `guard` never
+    /// started a shift.
+    pub fn find_sleepiest_minute_by_guard(&self, guard: u16) -> Option<i32> {
+        let mut max = 0;
+        let mut max_index = 0;
+
+        let sleep_minutes = self.minutes_asleep_by_guard.get(&guard)?;
+
+        for (i, &minutes) in sleep_minutes.iter().enumerate() {
+            if minutes > max {
+                max = minutes;
+                max_index = i;
+            }
+        }
+
+        return Some(max_index as i32);
+    }
+}
+
+pub const INPUT: Source<'static> = puzzle_input::crate_input!();
+
+const NO_SLEEP: &str = "No guard fell asleep";
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Schedule;
+    type PartOne = i32;
+    type PartTwo = i32;
+
+    fn parse(input: &str) -> Result<Schedule, ParseError> {
+        let mut records: Vec<Record> = parse_lines(input, Record::from_str)?;
+
+        records.sort();
+
+        return Ok(Schedule::new(&records));
+    }
+
+    fn part1(schedule: &Schedule) -> Result<i32, &'static str> {
+        let (sleepiest_guard, _) = schedule.find_sleepiest_guard().ok_or(NO_SLEEP)?;
+        let sleepiest_minute = schedule.find_sleepiest_minute_by_guard(sleepiest_guard).ok_or(NO_SLEEP)?;
+
+        return Ok(sleepiest_guard as i32 * sleepiest_minute);
+    }
+
+    fn part2(schedule: &Schedule) -> Result<i32, &'static str> {
+        let (most_frequent_guard, highest_frequence_minute) =
+            schedule.find_most_consistent_sleep_guard().ok_or(NO_SLEEP)?;
+
+        return Ok(most_frequent_guard as i32 * highest_frequence_minute);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day4;
+    use super::Record;
+    use super::GuardAction;
+    use super::RECORD_HINT;
+    use chrono::prelude::*;
+    use solution::ParseError;
+    use solution::ParseErrorKind;
+    use solution::Solution;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn test_wake_up_record() {
+        let result = "[1518-09-14 00:54] wakes up".parse::<Record>().unwrap();
+        let expected = Record {
+            date: Utc.with_ymd_and_hms(1518, 9, 14, 0, 54, 0).unwrap(),
+            action: GuardAction::WakeUp,
+        };
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_fall_asleep_record() {
+        let result = "[1518-09-14 00:54] falls asleep".parse::<Record>().unwrap();
+        let expected = Record {
+            date: Utc.with_ymd_and_hms(1518, 9, 14, 0, 54, 0).unwrap(),
+            action: GuardAction::FallAsleep,
+        };
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_shift_starts_record() {
+        let result = "[1518-04-15 23:58] Guard #373 begins shift".parse::<Record>().unwrap();
+        let expected = Record {
+            date: Utc.with_ymd_and_hms(1518, 4, 15, 23, 58, 0).unwrap(),
+            action: GuardAction::StartShift(373),
+        };
+
+        assert_eq!(expected, result);
+    }
+
+    #[test]
+    fn test_unknown_action_record() {
+        let result = "[1518-04-15 23:58] falls awake".parse::<Record>();
+        let expected = ParseError::new(
+            ParseErrorKind::Expected("`begins shift`, `falls asleep` or `wakes up`"),
+            20,
+            "falls awake",
+        )
+        .with_hint(RECORD_HINT);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_invalid_guard_record() {
+        let result = "[1518-04-15 23:58] Guard #37x begins shift".parse::<Record>();
+        let expected = ParseError::new(ParseErrorKind::InvalidNumber, 27, "37x").with_hint(RECORD_HINT);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_invalid_date_record() {
+        let result = "[1518-13-15 23:58] wakes up".parse::<Record>();
+        let expected = ParseError::new(ParseErrorKind::InvalidDate, 2, "1518-13-15 23:58").with_hint(RECORD_HINT);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_sort_record() {
+        let first = Record {
+            date: Utc.with_ymd_and_hms(1518, 4, 15, 23, 58, 0).unwrap(),
+            action: GuardAction::StartShift(373),
+        };
+        let second = Record {
+            date: Utc.with_ymd_and_hms(1518, 9, 14, 0, 54, 0).unwrap(),
+            action: GuardAction::WakeUp,
+        };
+
+        assert_eq!(second.cmp(&first), Ordering::Greater);
+        assert_eq!(first.cmp(&second), Ordering::Less);
+        assert_eq!(first.cmp(&first), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_no_guard_asleep() {
+        let no_shift = Day4::parse("[1518-01-01 00:00] wakes up").unwrap();
+        let awake = Day4::parse("[1518-01-01 00:00] Guard #10 begins shift").unwrap();
+
+        assert_eq!(Day4::part1(&no_shift), Err("No guard fell asleep"));
+        assert_eq!(Day4::part2(&no_shift), Err("No guard fell asleep"));
+        assert_eq!(Day4::part1(&awake), Err("No guard fell asleep"));
+        assert_eq!(Day4::part2(&awake), Err("No guard fell asleep"));
+    }
+}
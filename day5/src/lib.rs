@@ -0,0 +1,127 @@
+extern crate rayon;
+
+use rayon::prelude::*;
+use puzzle_input::Source;
+use solution::ParseError;
+use solution::Solution;
+
+pub fn filter_unit(unit: char, input: &str) -> String {
+    input
+        .chars()
+        .filter(|&item| item != unit && item != upper_char(unit))
+        .collect::<String>()
+}
+
+pub fn filter_pairs(input: &str) -> String {
+    let mut next_input = input.to_string();
+    loop {
+        let pairs = make_pairs(&next_input);
+        let filtered = pairs
+            .iter()
+            .map(|&(x, y)| (cancels_out(x, y), (x, y)))
+            .collect::<Vec<(bool, (char, char))>>();
+
+        let unmarked = filtered
+            .iter()
+            .filter(|(marked, (_, _))| !marked)
+            .collect::<Vec<_>>();
+
+        if unmarked.len() == pairs.len() {
+            break;
+        }
+
+        next_input = "".to_string();
+        let mut iter = filtered.iter();
+        while let Some((marked, (x, _))) = iter.next() {
+            if !*marked {
+                next_input.push(*x);
+            } else {
+                iter.next();
+            }
+        }
+    }
+
+    return next_input;
+}
+
+fn make_pairs(input: &str) -> Vec<(char, char)> {
+    let mut skipped_input = input.to_string();
+    skipped_input.push(' ');
+    let skipped = skipped_input.chars().skip(1).collect::<String>();
+
+    input.chars().zip(skipped.chars()).collect()
+}
+
+fn cancels_out(x: char, y: char) -> bool {
+    (upper_char(x) == y || upper_char(y) == x) && x != y
+}
+
+fn upper_char(ch: char) -> char {
+    ch.to_uppercase()
+        .collect::<String>()
+        .chars()
+        .next()
+        .expect("Could not upper case")
+}
+
+pub const INPUT: Source<'static> = puzzle_input::crate_input!();
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = String;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        return Ok(input.lines().next().unwrap_or("").to_string());
+    }
+
+    fn part1(input: &String) -> Result<usize, &'static str> {
+        let output = filter_pairs(input);
+
+        return Ok(output.len());
+    }
+
+    fn part2(input: &String) -> Result<usize, &'static str> {
+        let chars = (97..=122).map(|ascii| ascii as u8 as char);
+
+        let filtered = chars
+            .map(|unit| filter_unit(unit, input))
+            .collect::<Vec<String>>();
+
+        return filtered
+            .par_iter()
+            .map(|s| filter_pairs(s))
+            .map(|s| s.len())
+            .min()
+            .ok_or("No polymer to react");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::cancels_out;
+    use super::filter_pairs;
+    use super::filter_unit;
+
+    #[test]
+    fn test_cancels_out() {
+        assert!(cancels_out('a', 'A'));
+        assert!(cancels_out('A', 'a'));
+        assert!(!cancels_out('a', 'a'));
+        assert!(!cancels_out('a', 'B'));
+    }
+
+    #[test]
+    fn test_filter_pairs() {
+        assert_eq!(filter_pairs("aA"), "");
+        assert_eq!(filter_pairs("abBA"), "");
+        assert_eq!(filter_pairs("aabAAB"), "aabAAB");
+    }
+
+    #[test]
+    fn test_filter_unit() {
+        assert_eq!(filter_unit('a', "dabAcCaCBAcCcaDA"), "dbcCCBcCcD");
+    }
+}
@@ -1,8 +1,9 @@
 mod point;
 
-use self::point::Point;
+pub use self::point::Point;
 use std::collections::HashSet;
 
+#[derive(Clone)]
 pub struct Board {
     board: Vec<Vec<i32>>,
     points: Vec<(i32, Point)>,
@@ -26,8 +27,8 @@ impl Board {
         let board = vec![vec![0; max_x + 2]; max_y + 2];
 
         return Board {
-            points: points,
-            board: board,
+            points,
+            board,
         };
     }
 
@@ -50,7 +51,7 @@ impl Board {
             .collect::<Vec<&(&i32, i32)>>();
 
         if mins.len() == 1 {
-            let (id, _) = mins.iter().nth(0).unwrap();
+            let (id, _) = mins.first().unwrap();
             return **id;
         } else {
             return 0;
@@ -102,12 +103,12 @@ impl Board {
         return non_infinate_areas;
     }
 
-    pub fn area_closest_to_all(&self) -> Vec<Point> {
+    pub fn area_closest_to_all(&self, max_distance: i32) -> Vec<Point> {
         let mut result: Vec<Point> = Vec::new();
         for (y, lane) in self.board.iter().enumerate() {
             for (x, _) in lane.iter().enumerate() {
                 let point = Point::new(x as i32, y as i32);
-                if self.distance_to_all(&point) < 10000 {
+                if self.distance_to_all(&point) < max_distance {
                     result.push(point);
                 }
             }
@@ -121,12 +122,12 @@ impl Board {
     }
 }
 
-impl From<Vec<String>> for Board {
-    fn from(input: Vec<String>) -> Board {
+impl From<Vec<Point>> for Board {
+    fn from(input: Vec<Point>) -> Board {
         let points = input
-            .iter()
+            .into_iter()
             .enumerate()
-            .map(|(i, coord)| ((i + 1) as i32, Point::from(coord)))
+            .map(|(i, point)| ((i + 1) as i32, point))
             .collect::<Vec<(i32, Point)>>();
 
         return Board::new(points);
@@ -1,3 +1,9 @@
+use solution::parse_number;
+use solution::ParseError;
+use solution::ParseErrorKind;
+use std::str::FromStr;
+
+#[derive(Clone)]
 pub struct Point {
     pub x: i32,
     pub y: i32,
@@ -12,12 +18,55 @@ impl Point {
     }
 }
 
-impl From<&String> for Point {
-    fn from(input: &String) -> Point {
-        let split = input.split(", ").collect::<Vec<&str>>();
-        Point::new(
-            split[0].parse::<i32>().unwrap(),
-            split[1].parse::<i32>().unwrap(),
-        )
+const POINT_HINT: &str = "expected `<x>, <y>`";
+
+fn parse_point(input: &str) -> Result<Point, ParseError> {
+    let separator = input
+        .find(", ")
+        .ok_or_else(|| ParseError::new(ParseErrorKind::Expected("`, `"), 1, input))?;
+    let (x, y) = (&input[..separator], &input[separator + 2..]);
+
+    Ok(Point::new(
+        parse_number(x, 1)?,
+        parse_number(y, x.chars().count() + 3)?,
+    ))
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Point, ParseError> {
+        parse_point(input).map_err(|error| error.with_hint(POINT_HINT))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Point;
+    use super::POINT_HINT;
+    use solution::ParseError;
+    use solution::ParseErrorKind;
+
+    #[test]
+    fn test_point_from_str() {
+        let point = "162, 168".parse::<Point>().unwrap();
+
+        assert_eq!((point.x, point.y), (163, 169));
+    }
+
+    #[test]
+    fn test_point_invalid_number() {
+        let result = "162, 1x8".parse::<Point>().map(|_| ());
+        let expected = ParseError::new(ParseErrorKind::InvalidNumber, 6, "1x8").with_hint(POINT_HINT);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_point_missing_separator() {
+        let result = "162 168".parse::<Point>().map(|_| ());
+        let expected = ParseError::new(ParseErrorKind::Expected("`, `"), 1, "162 168").with_hint(POINT_HINT);
+
+        assert_eq!(result, Err(expected));
     }
 }
@@ -0,0 +1,51 @@
+mod board;
+
+pub use board::Board;
+pub use board::Point;
+use puzzle_input::Source;
+use solution::parse_lines;
+use solution::ParseError;
+use solution::Solution;
+use std::str::FromStr;
+
+pub const INPUT: Source<'static> = puzzle_input::crate_input!();
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Board;
+    type PartOne = i32;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Board, ParseError> {
+        return parse_lines(input, Point::from_str).map(Board::from);
+    }
+
+    fn part1(board: &Board) -> Result<i32, &'static str> {
+        let mut board = board.clone();
+        board.mark_with_closest();
+        let areas = board.get_point_areas();
+
+        let max_non_infinate_area = areas.iter().map(|&(_, area)| area).max();
+        return max_non_infinate_area.ok_or("No finite area found");
+    }
+
+    fn part2(board: &Board) -> Result<usize, &'static str> {
+        let area_closest_to_all = board.area_closest_to_all(10000);
+
+        return Ok(area_closest_to_all.len());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Day6;
+    use solution::Solution;
+
+    #[test]
+    fn test_area_closest_to_all_example() {
+        let board = Day6::parse(include_str!("../../fixtures/day6/example")).unwrap();
+
+        assert_eq!(board.area_closest_to_all(32).len(), 16);
+    }
+}
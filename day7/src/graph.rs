@@ -1,24 +1,61 @@
 use std::collections::BTreeMap;
 use std::collections::HashSet;
+use std::str::FromStr;
 
-#[derive(Clone)]
+use solution::ParseError;
+use solution::ParseErrorKind;
+
+#[derive(Clone, Default)]
 pub struct Graph(BTreeMap<char, HashSet<char>>);
 
+#[derive(PartialEq, Debug)]
 pub struct Entry {
   name: char,
   dependency: char,
 }
 
-impl From<String> for Entry {
-  fn from(input: String) -> Entry {
-    let name = input.chars().nth(36).unwrap();
-    let dependency = input.chars().nth(5).unwrap();
+const ENTRY_FORMAT: &str = "Step _ must be finished before step _ can begin.";
+const EXPECTED_STEP: ParseErrorKind = ParseErrorKind::Expected("a step name from `A` to `Z`");
+
+const ENTRY_HINT: &str = "expected `Step <X> must be finished before step <Y> can begin.`";
+
+fn parse_entry(input: &str) -> Result<Entry, ParseError> {
+  let mut steps = Vec::new();
+  let mut chars = input.char_indices();
 
-    Entry {
-      name: name,
-      dependency: dependency,
+  for (index, expected) in ENTRY_FORMAT.chars().enumerate() {
+    let column = index + 1;
+    let (offset, found) = chars
+      .next()
+      .ok_or_else(|| ParseError::new(ParseErrorKind::InvalidFormat, column, ""))?;
+
+    if expected == '_' && found.is_ascii_uppercase() {
+      steps.push(found);
+    } else if expected == '_' {
+      let step = &input[offset..offset + found.len_utf8()];
+      return Err(ParseError::new(EXPECTED_STEP, column, step));
+    } else if expected != found {
+      return Err(ParseError::new(ParseErrorKind::InvalidFormat, column, &input[offset..]));
     }
   }
+
+  if let Some((offset, _)) = chars.next() {
+    let column = ENTRY_FORMAT.len() + 1;
+    return Err(ParseError::new(ParseErrorKind::Expected("end of line"), column, &input[offset..]));
+  }
+
+  Ok(Entry {
+    name: steps[1],
+    dependency: steps[0],
+  })
+}
+
+impl FromStr for Entry {
+  type Err = ParseError;
+
+  fn from_str(input: &str) -> Result<Entry, ParseError> {
+    parse_entry(input).map_err(|error| error.with_hint(ENTRY_HINT))
+  }
 }
 
 impl Graph {
@@ -27,8 +64,8 @@ impl Graph {
   }
 
   pub fn add_entry(&mut self, entry: Entry) {
-    self.0.entry(entry.dependency).or_insert(HashSet::new());
-    let dependencies = self.0.entry(entry.name).or_insert(HashSet::new());
+    self.0.entry(entry.dependency).or_default();
+    let dependencies = self.0.entry(entry.name).or_default();
     dependencies.insert(entry.dependency);
   }
 
@@ -46,13 +83,13 @@ impl<'a> GraphIterator<'a> {
   pub fn new(items: &'a BTreeMap<char, HashSet<char>>) -> GraphIterator<'a> {
     GraphIterator {
       met_dependencies: HashSet::new(),
-      items: items,
+      items,
     }
   }
 
   pub fn next_task(&self) -> Option<char> {
     for (key, val) in self.items.iter() {
-      if self.met_dependencies.is_superset(&val) && !self.met_dependencies.contains(key) {
+      if self.met_dependencies.is_superset(val) && !self.met_dependencies.contains(key) {
         return Some(*key);
       }
     }
@@ -64,7 +101,7 @@ impl<'a> GraphIterator<'a> {
     let mut result = HashSet::new();
 
     for (key, val) in self.items.iter() {
-      if self.met_dependencies.is_superset(&val) && !self.met_dependencies.contains(key) {
+      if self.met_dependencies.is_superset(val) && !self.met_dependencies.contains(key) {
         result.insert(*key);
       }
     }
@@ -87,3 +124,35 @@ impl<'a> Iterator for GraphIterator<'a> {
     return None;
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::Entry;
+  use super::ENTRY_HINT;
+  use solution::ParseError;
+  use solution::ParseErrorKind;
+
+  #[test]
+  fn test_entry_from_str() {
+    let entry = "Step C must be finished before step A can begin.".parse::<Entry>();
+
+    assert_eq!(entry, Ok(Entry { name: 'A', dependency: 'C' }));
+  }
+
+  #[test]
+  fn test_entry_invalid_step() {
+    let entry = "Step C must be finished before step a can begin.".parse::<Entry>();
+    let expected = ParseError::new(ParseErrorKind::Expected("a step name from `A` to `Z`"), 37, "a")
+      .with_hint(ENTRY_HINT);
+
+    assert_eq!(entry, Err(expected));
+  }
+
+  #[test]
+  fn test_entry_truncated() {
+    let entry = "Step C must be done".parse::<Entry>();
+    let expected = ParseError::new(ParseErrorKind::InvalidFormat, 16, "done").with_hint(ENTRY_HINT);
+
+    assert_eq!(entry, Err(expected));
+  }
+}
@@ -0,0 +1,94 @@
+pub mod graph;
+pub mod worker;
+
+use puzzle_input::Source;
+use solution::parse_lines;
+use solution::ParseError;
+use solution::Solution;
+use std::str::FromStr;
+
+use self::graph::Entry;
+use self::graph::Graph;
+
+use self::worker::BasicWorker;
+use self::worker::CompositeWorker;
+use self::worker::Worker;
+
+fn exercise_one(graph: Graph) -> String {
+    graph.iter().collect()
+}
+
+fn exercise_two(graph: Graph, nworkers: usize, base_time: u32) -> Result<u32, &'static str> {
+    let mut workers = CompositeWorker::new();
+    for _ in 0..nworkers {
+        workers.add_worker(Box::new(BasicWorker::with_base_time(base_time)));
+    }
+
+    let mut iter = graph.iter();
+
+    loop {
+        for task in iter.available_tasks() {
+            if workers
+                .working_on()
+                .is_none_or(|working_tasks| !working_tasks.contains(&task))
+                && workers.is_available()
+            {
+                workers.add_work(task)?;
+            }
+        }
+
+        if let Some(completed_tasks) = workers.work() {
+            for &task in completed_tasks.iter() {
+                iter.complete(task);
+            }
+        }
+
+        if iter.next_task().is_none() {
+            break;
+        }
+    }
+    return Ok(workers.time_worked());
+}
+
+pub const INPUT: Source<'static> = puzzle_input::crate_input!();
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Graph;
+    type PartOne = String;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Graph, ParseError> {
+        let entries = parse_lines(input, Entry::from_str)?;
+        let mut graph = Graph::new();
+
+        for entry in entries {
+            graph.add_entry(entry);
+        }
+
+        return Ok(graph);
+    }
+
+    fn part1(graph: &Graph) -> Result<String, &'static str> {
+        return Ok(exercise_one(graph.clone()));
+    }
+
+    fn part2(graph: &Graph) -> Result<u32, &'static str> {
+        return exercise_two(graph.clone(), 5, 60);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::exercise_two;
+    use super::Day7;
+    use solution::Solution;
+
+    #[test]
+    fn test_exercise_two_example() {
+        let graph = Day7::parse(include_str!("../../fixtures/day7/example")).unwrap();
+
+        assert_eq!(exercise_two(graph, 2, 0), Ok(15));
+    }
+}
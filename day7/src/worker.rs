@@ -10,19 +10,31 @@ pub trait Worker<Task> {
 }
 
 pub struct BasicWorker {
+  base_time: u32,
   work_time: u32,
   work: Vec<char>,
 }
 
 impl BasicWorker {
   pub fn new() -> BasicWorker {
+    BasicWorker::with_base_time(60)
+  }
+
+  pub fn with_base_time(base_time: u32) -> BasicWorker {
     BasicWorker {
+      base_time,
       work_time: 0,
       work: Vec::new(),
     }
   }
 }
 
+impl Default for BasicWorker {
+  fn default() -> BasicWorker {
+    BasicWorker::new()
+  }
+}
+
 impl Worker<char> for BasicWorker {
   fn is_available(&self) -> bool {
     self.work.is_empty()
@@ -33,7 +45,7 @@ impl Worker<char> for BasicWorker {
   }
 
   fn working_on(&self) -> Option<char> {
-    self.work.first().map(|task| task.clone())
+    self.work.first().copied()
   }
 
   fn add_work(&mut self, input: char) -> Result<(), &'static str> {
@@ -41,7 +53,7 @@ impl Worker<char> for BasicWorker {
       return Err("Can not add work to an already working worker.");
     }
 
-    let work_amount = (input as usize) - ('A' as usize) + 61;
+    let work_amount = (input as usize) - ('A' as usize) + 1 + self.base_time as usize;
     for _ in 0..work_amount {
       self.work.push(input);
     }
@@ -64,6 +76,7 @@ impl Worker<char> for BasicWorker {
   }
 }
 
+#[derive(Default)]
 pub struct CompositeWorker {
   work_time: u32,
   workers: Vec<Box<dyn Worker<char>>>,
@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::io::prelude::*;
+
+/// Where a puzzle input is read from.
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Source<'a> {
+    Path(&'a str),
+    Stdin,
+    Embedded(&'a str),
+}
+
+/// The `input` file next to the calling crate's `Cargo.toml`.
+#[macro_export]
+macro_rules! crate_input {
+    () => {
+        $crate::Source::Path(concat!(env!("CARGO_MANIFEST_DIR"), "/input"))
+    };
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Io { source: String, error: io::Error },
+    Empty { source: String },
+}
+
+impl<'a> Source<'a> {
+    pub fn from_arg(arg: &'a str) -> Source<'a> {
+        if arg == "-" {
+            return Source::Stdin;
+        }
+
+        return Source::Path(arg);
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        let content = match *self {
+            Source::Path(path) => fs::read_to_string(path),
+            Source::Stdin => {
+                let mut content = String::new();
+                io::stdin().read_to_string(&mut content).map(|_| content)
+            }
+            Source::Embedded(content) => Ok(content.to_string()),
+        };
+
+        let content = content.map_err(|error| InputError::Io {
+            source: self.to_string(),
+            error,
+        })?;
+
+        if content.trim().is_empty() {
+            return Err(InputError::Empty {
+                source: self.to_string(),
+            });
+        }
+
+        return Ok(content);
+    }
+}
+
+impl<'a> fmt::Display for Source<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Source::Path(path) => write!(f, "{}", path),
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::Embedded(_) => write!(f, "<embedded>"),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Io { source, error } => write!(f, "Could not read {}: {}", source, error),
+            InputError::Empty { source } => write!(f, "Input {} is empty", source),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { error, .. } => Some(error),
+            InputError::Empty { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InputError;
+    use super::Source;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(Source::from_arg("input"), Source::Path("input"));
+    }
+
+    #[test]
+    fn test_read_embedded() {
+        let source = Source::Embedded("+1\n-2\n");
+        assert_eq!(source.read().unwrap(), "+1\n-2\n");
+    }
+
+    #[test]
+    fn test_crate_input() {
+        let source = crate_input!();
+        assert_eq!(source, Source::Path(concat!(env!("CARGO_MANIFEST_DIR"), "/input")));
+    }
+
+    #[test]
+    fn test_read_missing_file() {
+        match Source::Path("does/not/exist").read() {
+            Err(InputError::Io { source, .. }) => assert_eq!(source, "does/not/exist"),
+            other => panic!("Expected io error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_read_empty() {
+        match Source::Embedded("\n").read() {
+            Err(InputError::Empty { source }) => assert_eq!(source, "<embedded>"),
+            other => panic!("Expected empty error, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+mod parse;
+
+pub use crate::parse::column;
+pub use crate::parse::parse_lines;
+pub use crate::parse::parse_number;
+pub use crate::parse::ParseError;
+pub use crate::parse::ParseErrorKind;
+
+use std::error;
+use std::fmt;
+use std::fmt::Display;
+
+/// A puzzle solution for a single day.
+///
+/// The puzzle input is parsed once into `Input`, which both parts then
+/// solve independently of each other.
+pub trait Solution {
+    type Input;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::PartOne, &'static str>;
+    fn part2(input: &Self::Input) -> Result<Self::PartTwo, &'static str>;
+}
+
+#[derive(PartialEq, Debug)]
+pub enum Error {
+    Parse(ParseError),
+    Solve(&'static str),
+}
+
+/// Parses `input` and solves the requested `part`, formatting the answer.
+pub fn solve<S: Solution>(part: u8, input: &str) -> Result<String, Error> {
+    let parsed = S::parse(input).map_err(Error::Parse)?;
+
+    let answer = match part {
+        1 => S::part1(&parsed).map(|answer| answer.to_string()),
+        2 => S::part2(&parsed).map(|answer| answer.to_string()),
+        _ => Err("Part must be 1 or 2"),
+    };
+
+    return answer.map_err(Error::Solve);
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(error) => write!(f, "Parse error on {}", error),
+            Error::Solve(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl error::Error for Error {}
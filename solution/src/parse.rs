@@ -0,0 +1,140 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum ParseErrorKind {
+    InvalidFormat,
+    InvalidNumber,
+    InvalidDate,
+    Expected(&'static str),
+}
+
+/// A malformed piece of puzzle input.
+///
+/// `line` and `column` are 1-based, with `column` counted in characters, and
+/// `text` is the offending part of the line. `hint` describes the format the
+/// whole line was expected to have.
+#[derive(PartialEq, Debug, Clone)]
+pub struct ParseError {
+    pub kind: ParseErrorKind,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub hint: Option<&'static str>,
+}
+
+impl ParseError {
+    pub fn new(kind: ParseErrorKind, column: usize, text: &str) -> ParseError {
+        ParseError {
+            kind,
+            line: 1,
+            column,
+            text: text.to_string(),
+            hint: None,
+        }
+    }
+
+    pub fn with_hint(self, hint: &'static str) -> ParseError {
+        ParseError {
+            hint: Some(hint),
+            ..self
+        }
+    }
+
+    pub fn at_line(self, line: usize) -> ParseError {
+        ParseError { line, ..self }
+    }
+
+    /// Moves the error right by `columns`, for errors found in a substring.
+    pub fn shift(self, columns: usize) -> ParseError {
+        ParseError {
+            column: self.column + columns,
+            ..self
+        }
+    }
+}
+
+/// The 1-based character column of the byte `offset` into `line`.
+pub fn column(line: &str, offset: usize) -> usize {
+    line[..offset].chars().count() + 1
+}
+
+pub fn parse_number<T: FromStr>(text: &str, column: usize) -> Result<T, ParseError> {
+    text.parse::<T>()
+        .map_err(|_| ParseError::new(ParseErrorKind::InvalidNumber, column, text))
+}
+
+/// Parses every line of `input`, tagging errors with their line number.
+pub fn parse_lines<T, F>(input: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse(line).map_err(|error| error.at_line(index + 1)))
+        .collect()
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidFormat => write!(f, "invalid format"),
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::InvalidDate => write!(f, "invalid date"),
+            ParseErrorKind::Expected(expected) => write!(f, "expected {}", expected),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} `{}`",
+            self.line, self.column, self.kind, self.text
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::column;
+    use super::parse_lines;
+    use super::parse_number;
+    use super::ParseError;
+    use super::ParseErrorKind;
+
+    #[test]
+    fn test_column() {
+        assert_eq!(column("#1 @ 2,3: 4x5", 0), 1);
+        assert_eq!(column("#1 @ 2,3: 4x5", 5), 6);
+        assert_eq!(column("é @ 2", 3), 3);
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number::<i32>("-15", 1), Ok(-15));
+        assert_eq!(
+            parse_number::<u8>("x", 4),
+            Err(ParseError::new(ParseErrorKind::InvalidNumber, 4, "x"))
+        );
+    }
+
+    #[test]
+    fn test_parse_lines_reports_line() {
+        let result = parse_lines("1\n2\nthree\n4", |line| parse_number::<i32>(line, 1));
+        let expected = ParseError::new(ParseErrorKind::InvalidNumber, 1, "three").at_line(3);
+
+        assert_eq!(result, Err(expected));
+    }
+
+    #[test]
+    fn test_display() {
+        let error = ParseError::new(ParseErrorKind::Expected("`x`"), 3, "4by5").at_line(12);
+        assert_eq!(error.to_string(), "line 12, column 3: expected `x` `4by5`");
+    }
+}